@@ -10,19 +10,59 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Literal, Span};
 
 struct FixedPointMacroInput {
-    float: syn::LitFloat,
+    number: isize,
+    decimal_len: u8,
+    suffix: String,
     decimal_length: Option<syn::LitInt>,
 }
 
+/// Parse string literal as `[+-]digits[.digits]`
+fn parse_str(literal: &syn::LitStr) -> syn::Result<(isize, u8)> {
+    let value = literal.value();
+    let error = |message| Err(syn::Error::new(literal.span(), message));
+    let unsigned = value.strip_prefix(['-', '+']).unwrap_or(&value);
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    if !is_digits(integer) || !fraction.map(is_digits).unwrap_or(true) {
+        return error("Malformed fixed-point number");
+    }
+    let fraction = fraction.unwrap_or_default();
+    let number = match format!("{}{}", integer, fraction).parse::<isize>() {
+        Ok(number) if value.starts_with('-') => -number,
+        Ok(number) => number,
+        Err(_) => return error("Number overflow"),
+    };
+    Ok((number, fraction.len() as u8))
+}
+
 impl syn::parse::Parse for FixedPointMacroInput {
     fn parse(tokens: syn::parse::ParseStream) -> syn::Result<Self> {
-        let float: syn::LitFloat = tokens.parse()?;
+        let (number, decimal_len, suffix) = if tokens.peek(syn::LitStr) {
+            let (number, decimal_len) = parse_str(&tokens.parse()?)?;
+            (number, decimal_len, String::new())
+        } else if tokens.peek(syn::LitFloat) {
+            let float: syn::LitFloat = tokens.parse()?;
+            let num_string = float.base10_digits();
+            let number = num_string.replace('.', "").parse::<isize>().unwrap();
+            let decimal = num_string.rsplit('.').next().unwrap();
+            let decimal_len = decimal.chars().filter(|&c| c != '_').count() as u8;
+            (number, decimal_len, float.suffix().to_owned())
+        } else {
+            // Integer literal may be hexadecimal, octal or binary, e.g. `0x10u16`
+            let integer: syn::LitInt = tokens.parse()?;
+            (integer.base10_parse()?, 0, integer.suffix().to_owned())
+        };
         let decimal_length = match tokens.parse::<Token![,]>() {
             Ok(_) => Some(tokens.parse::<syn::LitInt>()?),
             Err(_) => None,
         };
         Ok(FixedPointMacroInput {
-            float,
+            number,
+            decimal_len,
+            suffix,
             decimal_length,
         })
     }
@@ -31,11 +71,8 @@ impl syn::parse::Parse for FixedPointMacroInput {
 #[proc_macro]
 pub fn fixed(tokens: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as FixedPointMacroInput);
-    let num_string = input.float.base10_digits();
-    let mut number = num_string.replace('.', "").parse::<isize>().unwrap();
-    let decimal = num_string.rsplit('.').next().unwrap();
-    let decimal_len = decimal.chars().filter(|&c| c != '_').count() as u8;
-    if input.float.suffix() == "" && input.decimal_length.is_none() {
+    let (mut number, decimal_len) = (input.number, input.decimal_len);
+    if input.suffix.is_empty() && input.decimal_length.is_none() {
         let number = Literal::isize_unsuffixed(number);
         let decimal_len = Literal::u8_unsuffixed(decimal_len);
         return quote!(fixed_point::FixedPoint::new(#number, #decimal_len)).into();
@@ -44,15 +81,19 @@ pub fn fixed(tokens: TokenStream) -> TokenStream {
         .decimal_length
         .map(|x| x.base10_digits().parse().unwrap())
         .unwrap_or(decimal_len);
+    let error = |s| syn::Error::new(Span::call_site(), s).to_compile_error();
     if decimal_length < decimal_len {
-        let error = |s| syn::Error::new(Span::call_site(), s).to_compile_error();
         return error("Insufficient precision").into();
     }
-    number = number * 10_isize.pow((decimal_length - decimal_len) as u32);
+    let exp = 10_isize.checked_pow((decimal_length - decimal_len) as u32);
+    number = match exp.and_then(|exp| number.checked_mul(exp)) {
+        Some(number) => number,
+        None => return error("Decimal length overflow").into(),
+    };
     let unsuffixed = Literal::isize_unsuffixed(number);
-    if input.float.suffix() == "" {
+    if input.suffix.is_empty() {
         return quote!(fixed_point::FixedPoint(#unsuffixed)).into();
     }
-    let type_ = Ident::new(input.float.suffix(), Span::call_site());
+    let type_ = Ident::new(&input.suffix, Span::call_site());
     quote!(fixed_point::FixedPoint::<#type_, #decimal_length>(#unsuffixed)).into()
 }
@@ -1,4 +1,4 @@
-use fixed_point::FixedPoint;
+use fixed_point::{AnyFixed, FixedPoint, FixedRange, MovingAverage, ParseError};
 use macros::fixed;
 
 #[test]
@@ -54,11 +54,24 @@ fn test_fixed_point_macro() {
 
 #[test]
 fn test_malformed() {
-    assert_eq!(Err(()), "".parse::<FixedPoint<u16, 4>>());
-    assert_eq!(Err(()), "1.".parse::<FixedPoint<u16, 4>>());
-    assert_eq!(Err(()), ".1".parse::<FixedPoint<u16, 4>>());
-    assert_eq!(Err(()), "-1.0".parse::<FixedPoint<u16, 4>>());
-    assert_eq!(Err(()), "10.0".parse::<FixedPoint<u16, 4>>());
+    assert_eq!(Err(ParseError::Empty), "".parse::<FixedPoint<u16, 4>>());
+    assert_eq!(Err(ParseError::Empty), "1.".parse::<FixedPoint<u16, 4>>());
+    assert_eq!(Err(ParseError::Empty), ".1".parse::<FixedPoint<u16, 4>>());
+    assert_eq!(
+        Err(ParseError::Overflow),
+        "-1.0".parse::<FixedPoint<u16, 4>>()
+    );
+    assert_eq!(
+        Err(ParseError::Overflow),
+        "10.0".parse::<FixedPoint<u16, 4>>()
+    );
+    let invalid = |c| Err(ParseError::InvalidDigit(c));
+    assert_eq!(invalid('a'), "1a.5".parse::<FixedPoint<i32, 2>>());
+    assert_eq!(invalid('x'), "0x1.5".parse::<FixedPoint<i32, 2>>());
+    assert_eq!(invalid('e'), "1.5e2".parse::<FixedPoint<i32, 2>>());
+    assert_eq!(invalid('.'), "1.2.3".parse::<FixedPoint<i32, 2>>());
+    assert_eq!(invalid('-'), "--1".parse::<FixedPoint<i32, 2>>());
+    assert_eq!(invalid(' '), " 1".parse::<FixedPoint<i32, 2>>());
 }
 
 #[test]
@@ -68,3 +81,1620 @@ fn test_i8() {
     let decimal = fixed!(0.0u8);
     assert_eq!("0.0", format!("{decimal}"));
 }
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
+
+#[test]
+fn test_try_new() {
+    assert_eq!(Some(FixedPoint(1100)), FixedPoint::<i16, 3>::try_new(11, 1));
+    assert_eq!(
+        Some(FixedPoint(-110)),
+        FixedPoint::<i16, 2>::try_new(-11, 1)
+    );
+    assert_eq!(None, FixedPoint::<i16, 2>::try_new(1234, 3));
+    assert_eq!(None, FixedPoint::<i16, 2>::try_new(1000, 0));
+}
+
+#[test]
+fn test_new() {
+    assert_eq!("1.1", format!("{}", FixedPoint::<i32, 2>::new(11, 1)));
+    assert_eq!("0.12", format!("{}", FixedPoint::<i32, 2>::new(1234, 4)));
+    assert_eq!("12.34", format!("{}", FixedPoint::<i32, 2>::new(123456, 4)));
+    assert_eq!(
+        "-12.34",
+        format!("{}", FixedPoint::<i32, 2>::new(-123456, 4))
+    );
+    assert_eq!("1.0", format!("{}", FixedPoint::<i32, 0>::new(19, 1)));
+}
+
+#[test]
+fn test_filled() {
+    let buffer: [FixedPoint<i16, 2>; 4] = FixedPoint::filled(fixed!(1.5i16, 2));
+    assert!(buffer.iter().all(|&v| v == fixed!(1.5i16, 2)));
+}
+
+#[test]
+fn test_as_ref() {
+    let mut decimal = fixed!(1.5i16, 2);
+    assert_eq!(&150, decimal.as_ref());
+    *decimal.as_mut() += 1;
+    assert_eq!("1.51", format!("{decimal}"));
+}
+
+#[test]
+fn test_exp() {
+    assert_eq!(1, FixedPoint::<u8, 0>::EXP_T);
+    assert_eq!(100, FixedPoint::<u8, 2>::EXP_T);
+    assert_eq!(10_i16.pow(4), FixedPoint::<i16, 4>::EXP_T);
+    assert_eq!(10_i64.pow(18), FixedPoint::<i64, 18>::EXP_T);
+    assert_eq!(10_u128.pow(38), FixedPoint::<u128, 38>::EXP_T);
+    assert_eq!(1000, FixedPoint::<i32, 3>(0).exp());
+    assert_eq!(10_u128.pow(20), FixedPoint::<u128, 20>(1).exp_t());
+    assert_eq!(10_i16.pow(4), FixedPoint::<i16, 4>(1).exp_t());
+    let decimal = fixed!(-12.345i32, 3);
+    assert_eq!((-12, -345), (decimal.integer(), decimal.decimal()));
+}
+
+#[test]
+fn test_rescale() {
+    let decimal = fixed!(-1.25i16, 2);
+    assert_eq!(fixed!(-1.2i16, 1), decimal.rescale::<1>());
+    assert_eq!(fixed!(-1.25i16, 4), decimal.rescale::<4>());
+    assert_eq!(Some(fixed!(-1.25i16, 4)), decimal.checked_rescale::<4>());
+    assert_eq!(fixed!(-1.25i16, 4), decimal.saturating_rescale::<4>());
+    let decimal = fixed!(-100.0i16, 1);
+    assert_eq!(None, decimal.checked_rescale::<3>());
+    assert_eq!(FixedPoint(i16::MIN), decimal.saturating_rescale::<3>());
+    let decimal = fixed!(100.0i16, 1);
+    assert_eq!(FixedPoint(i16::MAX), decimal.saturating_rescale::<3>());
+}
+
+#[test]
+fn test_display_alternate() {
+    assert_eq!("5.0", format!("{}", fixed!(5.0i32, 2)));
+    assert_eq!("5", format!("{:#}", fixed!(5.0i32, 2)));
+    assert_eq!("-5", format!("{:#}", fixed!(-5.0i32, 2)));
+    assert_eq!("5.5", format!("{:#}", fixed!(5.50i32, 2)));
+}
+
+#[test]
+fn test_scalar_add_sub() {
+    let mut decimal = fixed!(1.25i16, 2);
+    assert_eq!(fixed!(3.25i16, 2), decimal + 2);
+    assert_eq!(fixed!(-0.75i16, 2), decimal - 2);
+    decimal += 1;
+    assert_eq!(fixed!(2.25i16, 2), decimal);
+    decimal -= 3;
+    assert_eq!(fixed!(-0.75i16, 2), decimal);
+}
+
+#[test]
+fn test_pow10() {
+    let mut expected = 1_u128;
+    for n in 0..=38 {
+        assert_eq!(Some(expected), fixed_point::pow10(n));
+        expected = expected.wrapping_mul(10);
+    }
+    assert_eq!(None, fixed_point::pow10(39));
+    assert_eq!(None, fixed_point::pow10(u32::MAX));
+}
+
+#[test]
+fn test_negative_zero() {
+    let zero: FixedPoint<i32, 2> = "0".parse().unwrap();
+    for string in ["-0", "-0.0", "-0.00", "-0.001"] {
+        let decimal: FixedPoint<i32, 2> = string.parse().unwrap();
+        assert_eq!(zero, decimal);
+        assert_eq!("0.0", format!("{decimal}"));
+    }
+}
+
+#[test]
+fn test_sort() {
+    let mut values = [
+        fixed!(1.5i16, 1),
+        fixed!(-2.0i16, 1),
+        fixed!(0.1i16, 1),
+        fixed!(-0.1i16, 1),
+    ];
+    FixedPoint::sort_ascending(&mut values);
+    let expected = [
+        fixed!(-2.0i16, 1),
+        fixed!(-0.1i16, 1),
+        fixed!(0.1i16, 1),
+        fixed!(1.5i16, 1),
+    ];
+    assert_eq!(expected, values);
+    FixedPoint::sort_descending(&mut values);
+    let expected = [
+        fixed!(1.5i16, 1),
+        fixed!(0.1i16, 1),
+        fixed!(-0.1i16, 1),
+        fixed!(-2.0i16, 1),
+    ];
+    assert_eq!(expected, values);
+}
+
+#[test]
+fn test_checked_rem() {
+    let decimal = fixed!(-7.5i16, 2);
+    assert_eq!(
+        Some(fixed!(-1.5i16, 2)),
+        decimal.checked_rem(fixed!(2.0i16, 2))
+    );
+    assert_eq!(Some(fixed!(-1.5i16, 2)), decimal.checked_rem_int(2));
+    assert_eq!(Some(decimal), decimal.checked_rem_int(1000));
+    assert_eq!(None, decimal.checked_rem(FixedPoint(0)));
+    assert_eq!(None, decimal.checked_rem_int(0));
+    assert_eq!(
+        None,
+        FixedPoint::<i16, 2>(i16::MIN).checked_rem(FixedPoint(-1))
+    );
+}
+
+#[test]
+fn test_is_integer() {
+    assert!(fixed!(5.0i32, 2).is_integer());
+    assert!(!fixed!(5.5i32, 2).is_integer());
+    assert!(fixed!(-5.0i32, 2).is_integer());
+    assert!(!fixed!(-0.01i32, 2).is_integer());
+    assert!(FixedPoint::<i32, 0>(7).is_integer());
+}
+
+#[test]
+fn test_fractional_digits() {
+    assert_eq!(1, fixed!(1.100i32, 3).fractional_digits());
+    assert_eq!(3, fixed!(1.101i32, 3).fractional_digits());
+    assert_eq!(2, fixed!(-0.25i32, 3).fractional_digits());
+    assert_eq!(0, fixed!(5.0i32, 3).fractional_digits());
+    assert_eq!(0, FixedPoint::<i32, 0>(5).fractional_digits());
+}
+
+#[test]
+fn test_midpoint() {
+    let max = FixedPoint::<i16, 2>(i16::MAX);
+    assert_eq!(
+        FixedPoint(i16::MAX - 1),
+        max.midpoint(FixedPoint(i16::MAX - 2))
+    );
+    assert_eq!(FixedPoint(-1), max.midpoint(FixedPoint(i16::MIN)));
+    assert_eq!(
+        fixed!(1.25i16, 2),
+        fixed!(1.0i16, 2).midpoint(fixed!(1.5i16, 2))
+    );
+    assert_eq!(
+        fixed!(-0.01i16, 2),
+        fixed!(-0.01i16, 2).midpoint(fixed!(0.0i16, 2))
+    );
+    let max = FixedPoint::<u8, 2>(u8::MAX);
+    assert_eq!(
+        FixedPoint(u8::MAX - 1),
+        max.midpoint(FixedPoint(u8::MAX - 2))
+    );
+}
+
+#[test]
+fn test_mul() {
+    assert_eq!(fixed!(3.75i32, 2), fixed!(2.5i32, 2) * fixed!(1.5i32, 2));
+    assert_eq!(fixed!(-3.75i32, 2), fixed!(-2.5i32, 2) * fixed!(1.5i32, 2));
+    assert_eq!(fixed!(0.18i32, 2), fixed!(1.5i32, 2) * fixed!(0.1234i32, 4));
+    assert_eq!(
+        fixed!(0.1851i32, 4),
+        fixed!(0.1234i32, 4) * fixed!(1.5i32, 2)
+    );
+    // Raw products overflow backing type while results fit
+    let ten = FixedPoint::<i32, 4>(100000);
+    assert_eq!(FixedPoint(1000000), ten * ten);
+    assert_eq!(fixed!(1.8i8, 1), fixed!(1.2i8, 1) * fixed!(1.5i8, 1));
+    assert_eq!(fixed!(-1.8i8, 1), fixed!(1.2i8, 1) * fixed!(-1.5i8, 1));
+    let big = FixedPoint::<u64, 9>(u64::MAX);
+    assert_eq!(big, big * FixedPoint::<u64, 9>(1_000_000_000));
+}
+
+#[test]
+fn test_arbitrary() {
+    use arbitrary::{Arbitrary, Unstructured};
+    let mut unstructured = Unstructured::new(&[0x96, 0x00, 0xff, 0xff]);
+    let decimal = FixedPoint::<i16, 2>::arbitrary(&mut unstructured).unwrap();
+    assert_eq!(fixed!(1.5i16, 2), decimal);
+    let decimal = FixedPoint::<i16, 2>::arbitrary(&mut unstructured).unwrap();
+    assert_eq!(fixed!(-0.01i16, 2), decimal);
+}
+
+#[test]
+fn test_add_sub() {
+    let mut decimal = fixed!(1.25i16, 2) + fixed!(-2.5i16, 2);
+    assert_eq!(fixed!(-1.25i16, 2), decimal);
+    assert_eq!(fixed!(-2.5i16, 2), decimal - fixed!(1.25i16, 2));
+    decimal += fixed!(0.5i16, 2);
+    assert_eq!(fixed!(-0.75i16, 2), decimal);
+    decimal -= fixed!(0.5i16, 2);
+    assert_eq!(fixed!(-1.25i16, 2), decimal);
+}
+
+proptest::proptest! {
+    #[test]
+    fn test_add_sub_inverse(
+        a in fixed_point::any_fixed_point::<i32, 3>(),
+        b in fixed_point::any_fixed_point::<i32, 3>(),
+    ) {
+        proptest::prop_assume!(a.0.checked_add(b.0).is_some());
+        proptest::prop_assert_eq!(a, a + b - b);
+    }
+
+    #[test]
+    fn test_f64_round_trip(raw in -(1i64 << 50)..=(1i64 << 50)) {
+        let value = FixedPoint::<i64, 6>(raw);
+        proptest::prop_assert_eq!(FixedPoint::from_f64(value.to_f64()), Some(value));
+        let value = FixedPoint::<i64, 2>(raw);
+        proptest::prop_assert_eq!(FixedPoint::from_f64(value.to_f64()), Some(value));
+    }
+}
+
+#[test]
+fn test_checked_abs() {
+    assert_eq!(None, FixedPoint::<i16, 2>(i16::MIN).checked_abs());
+    assert_eq!(Some(fixed!(1.5i16, 2)), fixed!(-1.5i16, 2).checked_abs());
+    assert_eq!(Some(fixed!(1.5i16, 2)), fixed!(1.5i16, 2).checked_abs());
+    assert_eq!(Some(fixed!(1.5u16, 2)), fixed!(1.5u16, 2).checked_abs());
+}
+
+#[test]
+fn test_percent_string() {
+    assert_eq!("25%", fixed!(0.25i32, 4).to_percent_string());
+    assert_eq!("12.5%", fixed!(0.125i32, 4).to_percent_string());
+    assert_eq!("100%", fixed!(1.0i32, 4).to_percent_string());
+    assert_eq!("-0.01%", fixed!(-0.0001i32, 4).to_percent_string());
+    assert_eq!("100%", FixedPoint::<u8, 2>(100).to_percent_string());
+    assert_eq!("255%", FixedPoint::<u8, 2>(255).to_percent_string());
+    assert_eq!("25%", FixedPoint::<i16, 4>(2500).to_percent_string());
+    assert_eq!("-327.67%", FixedPoint::<i16, 4>(-32767).to_percent_string());
+    assert_eq!("12700%", FixedPoint::<i8, 0>(127).to_percent_string());
+    assert_eq!("-50%", FixedPoint::<i8, 1>(-5).to_percent_string());
+}
+
+#[test]
+fn test_to_i32() {
+    assert_eq!(Some(-12), fixed!(-12.99i64, 2).try_to_i32());
+    assert_eq!(12, fixed!(12.99i64, 2).to_i32());
+    assert_eq!(None, FixedPoint::<i64, 2>(i64::MAX).try_to_i32());
+}
+
+#[test]
+#[should_panic(expected = "Integer part out of range of i32")]
+fn test_to_i32_overflow() {
+    let _ = FixedPoint::<u64, 2>(u64::MAX).to_i32();
+}
+
+mod zero_decimal_length {
+    use fixed_point::FixedPoint;
+
+    type Integer = FixedPoint<i32, 0>;
+
+    #[test]
+    fn test_format() {
+        assert_eq!("0.0", format!("{}", Integer::default()));
+        assert_eq!("-7.0", format!("{}", FixedPoint::<i32, 0>(-7)));
+        assert_eq!("7", format!("{:#}", FixedPoint::<i32, 0>(7)));
+        assert_eq!(
+            (-7, 0),
+            (
+                FixedPoint::<i32, 0>(-7).integer(),
+                FixedPoint::<i32, 0>(-7).decimal()
+            )
+        );
+        assert_eq!(0, FixedPoint::<i32, 0>(7).fractional_digits());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(FixedPoint(12)), "12".parse::<Integer>());
+        assert_eq!(Ok(FixedPoint(12)), "12.99".parse::<Integer>());
+        assert_eq!(Ok(FixedPoint(-12)), "-12.5".parse::<Integer>());
+        assert_eq!(FixedPoint(12), Integer::new(129, 1));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b): (Integer, Integer) = (FixedPoint(7), FixedPoint(-2));
+        assert_eq!(FixedPoint(5), a + b);
+        assert_eq!(FixedPoint(9), a - b);
+        assert_eq!(FixedPoint(-14), a * b);
+        assert_eq!(FixedPoint(3), a / 2);
+        assert_eq!(FixedPoint(10), a + 3);
+        assert_eq!(Some(FixedPoint(1)), a.checked_rem(b));
+        assert_eq!(FixedPoint(700), a.rescale::<2>());
+        assert_eq!(FixedPoint(7), FixedPoint::<i32, 2>(799).rescale::<0>());
+    }
+}
+
+#[test]
+fn test_from_scaled_units() {
+    assert_eq!(
+        fixed!(1.5i32, 3),
+        FixedPoint::<i32, 3>::from_scaled_units(1500)
+    );
+    assert_eq!(fixed!(1.5i32, 3), FixedPoint::from_millis(1500));
+    assert_eq!(fixed!(-1.5i32, 6), FixedPoint::from_micros(-1_500_000));
+    assert_eq!(1500, FixedPoint::<i32, 3>::from_raw(1500).raw());
+}
+
+#[test]
+fn test_display_precision() {
+    assert_eq!("1.3", format!("{:.1}", fixed!(1.25i32, 2)));
+    assert_eq!("1.2", format!("{:.1}", fixed!(1.24i32, 2)));
+    assert_eq!("-1.3", format!("{:.1}", fixed!(-1.25i32, 2)));
+    assert_eq!("1.0", format!("{:.1}", fixed!(0.95i32, 2)));
+    assert_eq!("0.0", format!("{:.1}", fixed!(-0.04i32, 2)));
+    assert_eq!("3", format!("{:.0}", fixed!(2.5i32, 2)));
+    assert_eq!("1.25", format!("{:.2}", fixed!(1.25i32, 2)));
+    assert_eq!("1.2500", format!("{:.4}", fixed!(1.25i32, 2)));
+    assert_eq!("5.00", format!("{:.2}", FixedPoint::<i32, 0>(5)));
+}
+
+#[test]
+fn test_sum() {
+    let values = [fixed!(1.5i16, 2), fixed!(-0.25i16, 2), fixed!(2.0i16, 2)];
+    assert_eq!(fixed!(3.25i16, 2), values.into_iter().sum());
+    assert_eq!(fixed!(3.25i16, 2), FixedPoint::saturating_sum(values));
+    let values = [fixed!(300.0i16, 2), fixed!(27.0i16, 2), fixed!(1.0i16, 2)];
+    assert_eq!(FixedPoint(i16::MAX), FixedPoint::saturating_sum(values));
+    let values = [
+        fixed!(-300.0i16, 2),
+        fixed!(-27.0i16, 2),
+        fixed!(-1.0i16, 2),
+    ];
+    assert_eq!(FixedPoint(i16::MIN), FixedPoint::saturating_sum(values));
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!((11, 1), fixed!(1.100i32, 3).normalize());
+    assert_eq!((-5, 0), fixed!(-5.000i32, 3).normalize());
+    assert_eq!((0, 0), fixed!(0.000i32, 3).normalize());
+    assert_eq!((1001, 3), fixed!(1.001i32, 3).normalize());
+}
+
+#[test]
+fn test_const_cmp() {
+    use core::cmp::Ordering;
+    const TABLE: [FixedPoint<i16, 2>; 3] = [FixedPoint(-150), FixedPoint(25), FixedPoint(300)];
+    const SORTED: bool = {
+        let mut i = 1;
+        let mut sorted = true;
+        while i < TABLE.len() {
+            sorted &= matches!(TABLE[i - 1].const_cmp(TABLE[i]), Ordering::Less);
+            i += 1;
+        }
+        sorted
+    };
+    const { assert!(SORTED) };
+    const EQUAL: Ordering = FixedPoint::<u8, 1>(5).const_cmp(FixedPoint(5));
+    assert_eq!(Ordering::Equal, EQUAL);
+    assert_eq!(
+        Ordering::Greater,
+        fixed!(1.5i16, 2).const_cmp(fixed!(-1.5i16, 2))
+    );
+}
+
+#[test]
+#[cfg(feature = "strict-arithmetic")]
+#[should_panic(expected = "fixed-point overflow")]
+fn test_strict_add() {
+    let _ = FixedPoint::<i16, 2>(i16::MAX) + FixedPoint(1);
+}
+
+#[test]
+#[cfg(feature = "strict-arithmetic")]
+#[should_panic(expected = "fixed-point overflow")]
+fn test_strict_sub() {
+    let _ = FixedPoint::<u16, 2>(0) - 1;
+}
+
+#[test]
+#[cfg(feature = "strict-arithmetic")]
+#[should_panic(expected = "fixed-point overflow")]
+fn test_strict_mul() {
+    let _ = fixed!(200.0i16, 2) * fixed!(2.0i16, 2);
+}
+
+#[test]
+#[cfg(not(feature = "strict-arithmetic"))]
+#[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+fn test_overflow_add() {
+    let sum = FixedPoint::<i16, 2>(i16::MAX) + FixedPoint(1);
+    assert_eq!(sum, FixedPoint(i16::MIN));
+}
+
+#[test]
+#[cfg(not(feature = "strict-arithmetic"))]
+#[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+fn test_overflow_sub() {
+    let difference = FixedPoint::<u16, 2>(0) - 1;
+    assert_eq!(difference, FixedPoint(u16::MAX - 99));
+}
+
+#[test]
+#[cfg(not(feature = "strict-arithmetic"))]
+#[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+fn test_overflow_mul() {
+    let product = FixedPoint::<i16, 0>(200) * FixedPoint::<i16, 0>(200);
+    assert_eq!(product, FixedPoint(-25536));
+}
+
+#[test]
+fn test_bits() {
+    let decimal = FixedPoint::<i16, 2>::from_bits(-150);
+    assert_eq!(fixed!(-1.5i16, 2), decimal);
+    assert_eq!(-150, decimal.to_bits());
+    assert_eq!(decimal.raw(), decimal.to_bits());
+}
+
+#[test]
+fn test_round() {
+    let cases = [
+        // value, round_to(0), round_ties_even_to(0)
+        (fixed!(0.5i32, 2), fixed!(1.0i32, 2), fixed!(0.0i32, 2)),
+        (fixed!(1.5i32, 2), fixed!(2.0i32, 2), fixed!(2.0i32, 2)),
+        (fixed!(2.5i32, 2), fixed!(3.0i32, 2), fixed!(2.0i32, 2)),
+        (fixed!(3.5i32, 2), fixed!(4.0i32, 2), fixed!(4.0i32, 2)),
+        (fixed!(-2.5i32, 2), fixed!(-3.0i32, 2), fixed!(-2.0i32, 2)),
+        (fixed!(-3.5i32, 2), fixed!(-4.0i32, 2), fixed!(-4.0i32, 2)),
+        (fixed!(2.51i32, 2), fixed!(3.0i32, 2), fixed!(3.0i32, 2)),
+        (fixed!(-2.49i32, 2), fixed!(-2.0i32, 2), fixed!(-2.0i32, 2)),
+    ];
+    for (value, half_up, ties_even) in cases {
+        assert_eq!(half_up, value.round_to(0), "{value}");
+        assert_eq!(ties_even, value.round_ties_even_to(0), "{value}");
+    }
+    assert_eq!(
+        fixed!(1.24i32, 3),
+        fixed!(1.245i32, 3).round_ties_even_to(2)
+    );
+    assert_eq!(fixed!(1.25i32, 3), fixed!(1.245i32, 3).round_to(2));
+    assert_eq!(fixed!(1.245i32, 3), fixed!(1.245i32, 3).round_to(3));
+    assert_eq!(fixed!(2.0u8, 1), fixed!(2.5u8, 1).round_ties_even_to(0));
+}
+
+#[test]
+fn test_display_wide() {
+    assert_eq!(
+        "18446744073709551.615",
+        format!("{}", FixedPoint::<u64, 3>(u64::MAX))
+    );
+    assert_eq!("1.5", format!("{}", fixed!(1.500u64, 3)));
+    let decimal = FixedPoint::<u128, 6>(123_456_789_000_000_000_000_000_001);
+    assert_eq!("123456789000000000000.000001", format!("{decimal}"));
+    let decimal = FixedPoint::<i64, 2>(-1_234_567_890_123);
+    assert_eq!("-12345678901.23", format!("{decimal}"));
+    assert_eq!("-12345678901.2", format!("{decimal:.1}"));
+    assert_eq!("-0.05", format!("{}", FixedPoint::<i64, 2>(-5)));
+}
+
+#[test]
+fn test_split_int_frac_str() {
+    let split = |integer: &str, fraction: &str| (integer.to_owned(), fraction.to_owned());
+    assert_eq!(split("-1", "5"), fixed!(-1.50i32, 2).split_int_frac_str());
+    assert_eq!(split("-0", "05"), fixed!(-0.05i32, 2).split_int_frac_str());
+    assert_eq!(split("12", ""), fixed!(12.00i32, 2).split_int_frac_str());
+}
+
+#[test]
+fn test_cumulative_sum() {
+    let values = [fixed!(1.0i16, 2), fixed!(2.0i16, 2), fixed!(3.0i16, 2)];
+    let sums: Vec<_> = FixedPoint::cumulative_sum(values).collect();
+    assert_eq!(
+        vec![fixed!(1.0i16, 2), fixed!(3.0i16, 2), fixed!(6.0i16, 2)],
+        sums
+    );
+    let values = [fixed!(300.0i16, 2), fixed!(30.0i16, 2)];
+    let sums: Vec<_> = FixedPoint::cumulative_sum(values).collect();
+    assert_eq!(
+        vec![
+            fixed!(300.0i16, 2),
+            FixedPoint(30000_i16.wrapping_add(3000))
+        ],
+        sums
+    );
+    let sums: Vec<_> = FixedPoint::saturating_cumulative_sum(values).collect();
+    assert_eq!(vec![fixed!(300.0i16, 2), FixedPoint(i16::MAX)], sums);
+}
+
+#[test]
+fn test_from_str_exact() {
+    type Decimal = FixedPoint<i32, 2>;
+    assert_eq!(Ok(fixed!(1.01i32, 2)), Decimal::from_str_exact("1.01"));
+    assert_eq!(Ok(fixed!(-1.5i32, 2)), Decimal::from_str_exact("-1.500"));
+    assert_eq!(Ok(fixed!(3.0i32, 2)), Decimal::from_str_exact("3"));
+    assert_eq!(
+        Err(ParseError::PrecisionLoss),
+        Decimal::from_str_exact("1.001")
+    );
+    assert_eq!(
+        Err(ParseError::InvalidDigit('x')),
+        Decimal::from_str_exact("1.00x")
+    );
+}
+
+#[test]
+fn test_to_rational() {
+    assert_eq!((50, 100), fixed!(0.50i32, 2).to_rational());
+    assert_eq!((1, 2), fixed!(0.50i32, 2).to_rational_reduced());
+    assert_eq!((-5, 4), fixed!(-1.25i32, 2).to_rational_reduced());
+    assert_eq!((3, 1), fixed!(3.00i32, 2).to_rational_reduced());
+    assert_eq!((0, 1), fixed!(0.00i32, 2).to_rational_reduced());
+    assert_eq!((1, 1000), fixed!(0.001u16, 3).to_rational_reduced());
+}
+
+#[test]
+fn test_assert_approx_eq() {
+    fixed_point::assert_fixed_approx_eq!(
+        fixed!(1.25i32, 2),
+        fixed!(1.26i32, 2),
+        fixed!(0.01i32, 2)
+    );
+    fixed_point::assert_fixed_approx_eq!(fixed!(1.3u8, 1), fixed!(1.2u8, 1), fixed!(0.1u8, 1));
+}
+
+#[test]
+#[should_panic(expected = "left: -1.25\n    right: -1.28\ntolerance: 0.02")]
+fn test_assert_approx_eq_failed() {
+    fixed_point::assert_fixed_approx_eq!(
+        fixed!(-1.25i32, 2),
+        fixed!(-1.28i32, 2),
+        fixed!(0.02i32, 2)
+    );
+}
+
+#[test]
+fn test_separator() {
+    type Decimal = FixedPoint<i32, 2>;
+    assert_eq!(
+        Ok(fixed!(1.5i32, 2)),
+        Decimal::from_str_with_separator("1,5", ',')
+    );
+    assert_eq!(
+        Ok(fixed!(-0.25i32, 2)),
+        Decimal::from_str_with_separator("-0,25", ',')
+    );
+    let invalid = Err(ParseError::InvalidDigit('.'));
+    assert_eq!(invalid, Decimal::from_str_with_separator("1.5", ','));
+    assert_eq!("1,5", fixed!(1.5i32, 2).fmt_with_separator(','));
+    assert_eq!("-3,0", fixed!(-3.0i32, 2).fmt_with_separator(','));
+}
+
+#[test]
+fn test_mul_int() {
+    assert_eq!(fixed!(-4.5i16, 2), fixed!(1.5i16, 2) * -3);
+    assert_eq!(
+        Some(fixed!(-4.5i16, 2)),
+        fixed!(1.5i16, 2).checked_mul_int(-3)
+    );
+    assert_eq!(
+        Some(fixed!(327.0i16, 2)),
+        fixed!(0.5i16, 2).checked_mul_int(654)
+    );
+    assert_eq!(None, fixed!(1.5i16, 2).checked_mul_int(219));
+    assert_eq!(None, fixed!(-1.5i16, 2).checked_mul_int(219));
+}
+
+#[test]
+fn test_default() {
+    assert_eq!("0.0", format!("{}", FixedPoint::<i32, 2>::default()));
+    assert_eq!(FixedPoint(0), FixedPoint::<u8, 2>::default());
+}
+
+#[test]
+fn test_mul_ratio() {
+    assert_eq!(fixed!(25.4i16, 2), fixed!(10.0i16, 2).mul_ratio(254, 100));
+    assert_eq!(fixed!(-3.33i16, 2), fixed!(-10.0i16, 2).mul_ratio(1, 3));
+    assert_eq!(FixedPoint(150), FixedPoint::<u8, 0>(200).mul_ratio(3, 4));
+}
+
+#[test]
+fn test_radix_macro() {
+    assert_eq!(fixed!(16.0u16, 2), fixed!(0x10u16, 2));
+    assert_eq!(fixed!(5i16, 1), fixed!(0b101i16, 1));
+    assert_eq!(fixed!(-8i16, 1), fixed!(-0o10i16, 1));
+    assert_eq!(fixed!(255u8, 0), fixed!(0xffu8));
+    assert_eq!(FixedPoint::<i32, 2>(150), fixed!(1.5, 2));
+}
+
+#[test]
+fn test_parse_prefix() {
+    let (value, offset) = FixedPoint::<i32, 2>::parse_prefix("1.5rest").unwrap();
+    assert_eq!((value, &"1.5rest"[offset..]), (fixed!(1.5i32, 2), "rest"));
+    let parse_prefix = FixedPoint::<i32, 2>::parse_prefix;
+    assert_eq!(parse_prefix("-12 34"), Ok((fixed!(-12i32, 2), 3)));
+    assert_eq!(parse_prefix("3.x"), Ok((fixed!(3i32, 2), 1)));
+    assert_eq!(parse_prefix("0.125;"), Ok((fixed!(0.12i32, 2), 5)));
+    assert_eq!(parse_prefix("abc"), Err(ParseError::Empty));
+    assert_eq!(parse_prefix("-"), Err(ParseError::Empty));
+}
+
+#[test]
+fn test_to_bytes_array() {
+    const N: usize = FixedPoint::<i8, 2>::DISPLAY_LENGTH;
+    for value in [fixed!(-1.28i8, 2), fixed!(0.5i8, 2), fixed!(1i8, 2)] {
+        let (bytes, length) = value.to_bytes_array::<N>();
+        assert_eq!(&bytes[..length], value.to_string().as_bytes());
+    }
+    let value = FixedPoint::<i64, 0>(i64::MIN);
+    let (bytes, length) = value.to_bytes_array::<{ FixedPoint::<i64, 0>::DISPLAY_LENGTH }>();
+    assert_eq!(&bytes[..length], value.to_string().as_bytes());
+    assert_eq!(length, FixedPoint::<i64, 0>::DISPLAY_LENGTH);
+}
+
+#[test]
+fn test_wrapping_rescale() {
+    assert_eq!(fixed!(1.5i16, 1).wrapping_rescale::<2>(), fixed!(1.5i16, 2));
+    assert_eq!(
+        fixed!(1.25i16, 2).wrapping_rescale::<1>(),
+        fixed!(1.2i16, 1)
+    );
+    let value = FixedPoint::<i16, 0>(1000).wrapping_rescale::<2>();
+    assert_eq!(value, FixedPoint(100000_i32 as i16));
+}
+
+#[test]
+fn test_into_string() {
+    assert_eq!(String::from(fixed!(1.5i32, 2)), "1.5");
+    let strings: Vec<String> = vec![fixed!(-2i32, 1), fixed!(0.2i32, 1)]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(strings, ["-2.0", "0.2"]);
+}
+
+#[test]
+fn test_powf() {
+    assert_eq!(fixed!(4i32, 2).powf(0.5), Some(fixed!(2i32, 2)));
+    assert_eq!(fixed!(2i32, 3).powf(1.5), Some(fixed!(2.828i32, 3)));
+    assert_eq!(fixed!(-4i32, 2).powf(0.5), None);
+    assert_eq!(fixed!(100i16, 2).powf(2.0), None);
+}
+
+#[test]
+fn test_ln_exp_log10() {
+    let e = fixed!(2.718282i32, 6);
+    assert_eq!(e.ln(), Some(fixed!(1i32, 6)));
+    assert_eq!(fixed!(1i32, 6).exp_e(), Some(e));
+    assert_eq!(fixed!(1000i32, 2).log10(), Some(fixed!(3i32, 2)));
+    assert_eq!(fixed!(0i32, 2).ln(), None);
+    assert_eq!(fixed!(-1i32, 2).log10(), None);
+    assert_eq!(fixed!(100i32, 2).exp_e(), None);
+}
+
+#[test]
+fn test_trigonometric() {
+    assert_eq!(fixed!(0i32, 4).sin(), Some(fixed!(0i32, 4)));
+    assert_eq!(fixed!(0i32, 4).cos(), Some(fixed!(1i32, 4)));
+    let quarter_pi = fixed!(0.785398i32, 6);
+    fixed_point::assert_fixed_approx_eq!(
+        quarter_pi.sin().unwrap(),
+        fixed!(0.707107i32, 6),
+        fixed!(0.000001i32, 6)
+    );
+    fixed_point::assert_fixed_approx_eq!(
+        quarter_pi.tan().unwrap(),
+        fixed!(1i32, 6),
+        fixed!(0.000001i32, 6)
+    );
+}
+
+#[test]
+fn test_try_into_f32() {
+    assert_eq!(fixed!(-1.25i64, 2).try_into_f32(), Some(-1.25));
+    let value = FixedPoint::<u128, 20>(u128::MAX);
+    assert_eq!(value.try_into_f32(), Some((u128::MAX as f64 / 1e20) as f32));
+}
+
+#[test]
+fn test_saturating_into() {
+    assert_eq!(
+        fixed!(1.5i32, 2).saturating_into::<i16>(),
+        fixed!(1.5i16, 2)
+    );
+    assert_eq!(
+        fixed!(400i32, 2).saturating_into::<i16>(),
+        FixedPoint(i16::MAX)
+    );
+    assert_eq!(
+        fixed!(-400i32, 2).saturating_into::<i16>(),
+        FixedPoint(i16::MIN)
+    );
+    assert_eq!(fixed!(-1i32, 2).saturating_into::<u16>(), FixedPoint(0));
+}
+
+#[test]
+fn test_to_fixed_string() {
+    assert_eq!(fixed!(1.5i32, 4).to_fixed_string(), "1.5000");
+    assert_eq!(fixed!(2i32, 2).to_fixed_string(), "2.00");
+    assert_eq!(fixed!(-0.01i32, 3).to_fixed_string(), "-0.010");
+    assert_eq!(fixed!(7i32, 0).to_fixed_string(), "7");
+}
+
+#[test]
+fn test_from_int_frac_str() {
+    let from_int_frac_str = FixedPoint::<i32, 2>::from_int_frac_str;
+    assert_eq!(from_int_frac_str("12", "34"), Ok(fixed!(12.34i32, 2)));
+    assert_eq!(from_int_frac_str("12", ""), Ok(fixed!(12i32, 2)));
+    assert_eq!(from_int_frac_str("1", "2399"), Ok(fixed!(1.23i32, 2)));
+    assert_eq!(from_int_frac_str("-1", "5"), Ok(fixed!(-1.5i32, 2)));
+    assert_eq!(
+        from_int_frac_str("1", "-5"),
+        Err(ParseError::InvalidDigit('-'))
+    );
+}
+
+#[test]
+fn test_fixed_range() {
+    let range = FixedRange::new(fixed!(-1i32, 2), fixed!(2.5i32, 2)).unwrap();
+    assert!(range.contains(fixed!(0.5i32, 2)));
+    assert!(range.contains(fixed!(2.5i32, 2)));
+    assert!(!range.contains(fixed!(-1.01i32, 2)));
+    assert!(!range.contains(fixed!(2.51i32, 2)));
+    assert_eq!(range.clamp(fixed!(1i32, 2)), fixed!(1i32, 2));
+    assert_eq!(range.clamp(fixed!(-3i32, 2)), fixed!(-1i32, 2));
+    assert_eq!(range.clamp(fixed!(3i32, 2)), fixed!(2.5i32, 2));
+    assert_eq!(FixedRange::new(fixed!(1i32, 2), fixed!(0i32, 2)), None);
+}
+
+#[test]
+fn test_sub_signed() {
+    let diff: FixedPoint<i64, 2> = fixed!(1u32, 2).sub_signed(fixed!(2u32, 2));
+    assert_eq!(diff, fixed!(-1i64, 2));
+    assert_eq!(
+        fixed!(2.5u32, 2).sub_signed(fixed!(1u32, 2)),
+        fixed!(1.5i64, 2)
+    );
+    let diff = FixedPoint::<i8, 0>(i8::MIN).sub_signed(FixedPoint(i8::MAX));
+    assert_eq!(diff, FixedPoint(-255_i16));
+}
+
+#[test]
+fn test_to_padded_bytes() {
+    let mut buffer = [0; 8];
+    assert_eq!(
+        fixed!(1.5i32, 2).to_padded_bytes(6, b'0', &mut buffer),
+        Ok("0001.5")
+    );
+    assert_eq!(
+        fixed!(-1.5i32, 2).to_padded_bytes(6, b'0', &mut buffer),
+        Ok("-001.5")
+    );
+    assert_eq!(
+        fixed!(-1.5i32, 2).to_padded_bytes(8, b' ', &mut buffer),
+        Ok("    -1.5")
+    );
+    assert_eq!(
+        fixed!(12.25i32, 2).to_padded_bytes(5, b' ', &mut buffer),
+        Ok("12.25")
+    );
+    assert!(fixed!(12.25i32, 2)
+        .to_padded_bytes(4, b' ', &mut buffer)
+        .is_err());
+    assert!(fixed!(1.5i32, 2)
+        .to_padded_bytes(9, b' ', &mut buffer)
+        .is_err());
+}
+
+#[test]
+fn test_from_str_grouped() {
+    let from_str_grouped = FixedPoint::<i64, 6>::from_str_grouped;
+    assert_eq!(from_str_grouped("1.234 567"), Ok(fixed!(1.234567i64, 6)));
+    assert_eq!(from_str_grouped("-1.2 3"), Ok(fixed!(-1.23i64, 6)));
+    assert_eq!(from_str_grouped("12"), Ok(fixed!(12i64, 6)));
+    assert_eq!(from_str_grouped("1.234 567 89"), Ok(fixed!(1.234567i64, 6)));
+    assert_eq!(
+        from_str_grouped("1 234.5"),
+        Err(ParseError::InvalidDigit(' '))
+    );
+    assert_eq!(
+        from_str_grouped("1. 234"),
+        Err(ParseError::InvalidDigit(' '))
+    );
+    assert_eq!(
+        from_str_grouped("1.234 "),
+        Err(ParseError::InvalidDigit(' '))
+    );
+    assert_eq!(
+        from_str_grouped("1.23  4"),
+        Err(ParseError::InvalidDigit(' '))
+    );
+}
+
+#[test]
+fn test_scientific() {
+    assert_eq!(fixed!(150i32, 2).to_scientific_string(), "1.5e2");
+    assert_eq!(fixed!(-0.015i32, 3).to_scientific_string(), "-1.5e-2");
+    assert_eq!(fixed!(1i32, 2).to_scientific_string(), "1e0");
+    assert_eq!(fixed!(0i32, 2).to_scientific_string(), "0e0");
+    assert_eq!(fixed!(123.45i32, 2).to_scientific_string(), "1.2345e2");
+    assert!(fixed!(100i32, 2).is_power_of_ten());
+    assert!(fixed!(0.01i32, 2).is_power_of_ten());
+    assert!(fixed!(1i32, 2).is_power_of_ten());
+    assert!(!fixed!(0i32, 2).is_power_of_ten());
+    assert!(!fixed!(-10i32, 2).is_power_of_ten());
+    assert!(!fixed!(20i32, 2).is_power_of_ten());
+}
+
+#[test]
+fn test_map_range() {
+    let (zero, one, hundred) = (fixed!(0i32, 2), fixed!(1i32, 2), fixed!(100i32, 2));
+    assert_eq!(
+        fixed!(0.5i32, 2).map_range(zero, one, zero, hundred),
+        fixed!(50i32, 2)
+    );
+    assert_eq!(
+        fixed!(0.25i32, 2).map_range(zero, one, hundred, zero),
+        fixed!(75i32, 2)
+    );
+    let adc = FixedPoint::<u16, 0>(4095);
+    let (lo, hi) = (FixedPoint(0), FixedPoint(4095));
+    assert_eq!(adc.map_range(lo, hi, lo, FixedPoint(100)), FixedPoint(100));
+    let value = fixed!(3u16, 1).map_range(
+        fixed!(2u16, 1),
+        fixed!(4u16, 1),
+        fixed!(10u16, 1),
+        fixed!(0u16, 1),
+    );
+    assert_eq!(value, fixed!(5u16, 1));
+}
+
+#[test]
+fn test_map_range_full() {
+    let (lo, hi) = (FixedPoint::<u8, 0>(0), FixedPoint(250));
+    assert_eq!(FixedPoint(200).map_range(lo, hi, lo, hi), FixedPoint(200));
+    let (lo, hi) = (FixedPoint::<u8, 0>(0), FixedPoint(u8::MAX));
+    assert_eq!(FixedPoint(254).map_range(lo, hi, hi, lo), FixedPoint(1));
+    let (lo, hi) = (FixedPoint::<u16, 0>(0), FixedPoint(u16::MAX));
+    assert_eq!(
+        FixedPoint(60000).map_range(lo, hi, lo, hi),
+        FixedPoint(60000)
+    );
+    let (lo, hi) = (FixedPoint::<i32, 2>(i32::MIN), FixedPoint(i32::MAX));
+    assert_eq!(FixedPoint(-7).map_range(lo, hi, lo, hi), FixedPoint(-7));
+    assert_eq!(lo.map_range(lo, hi, hi, lo), hi);
+    assert_eq!(FixedPoint(0).map_range(lo, hi, hi, lo), FixedPoint(-1));
+    let (lo, hi) = (FixedPoint::<u64, 0>(0), FixedPoint(u64::MAX));
+    assert_eq!(
+        FixedPoint(u64::MAX - 1).map_range(lo, hi, lo, hi),
+        FixedPoint(u64::MAX - 1)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Empty input range")]
+fn test_map_range_empty() {
+    let one = fixed!(1i32, 2);
+    let _ = one.map_range(one, one, one, one);
+}
+
+#[test]
+fn test_clamp01() {
+    assert_eq!(fixed!(-0.5i32, 2).clamp01(), fixed!(0i32, 2));
+    assert_eq!(fixed!(0.75i32, 2).clamp01(), fixed!(0.75i32, 2));
+    assert_eq!(fixed!(1i32, 2).clamp01(), fixed!(1i32, 2));
+    assert_eq!(fixed!(1.01i32, 2).clamp01(), fixed!(1i32, 2));
+    assert_eq!(FixedPoint::<u8, 0>(7).clamp01(), FixedPoint(1));
+}
+
+#[test]
+fn test_moving_average() {
+    let mut average = MovingAverage::<i16, 1, 3>::new();
+    let samples = [
+        fixed!(3i16, 1),
+        fixed!(6i16, 1),
+        fixed!(9i16, 1),
+        fixed!(-3i16, 1),
+    ];
+    let averages: Vec<_> = samples.iter().map(|&v| average.push(v)).collect();
+    let expected = [
+        fixed!(3i16, 1),
+        fixed!(4.5i16, 1),
+        fixed!(6i16, 1),
+        fixed!(4i16, 1),
+    ];
+    assert_eq!(averages, expected);
+
+    let mut average = MovingAverage::<u8, 0, 2>::default();
+    assert_eq!(average.push(FixedPoint(250)), FixedPoint(250));
+    assert_eq!(average.push(FixedPoint(240)), FixedPoint(245));
+    assert_eq!(average.push(FixedPoint(1)), FixedPoint(120));
+
+    let mut average = MovingAverage::<u8, 0, 256>::new();
+    for _ in 0..300 {
+        assert_eq!(average.push(FixedPoint(u8::MAX)), FixedPoint(u8::MAX));
+    }
+    let mut average = MovingAverage::<i8, 0, 256>::new();
+    for _ in 0..300 {
+        assert_eq!(average.push(FixedPoint(i8::MIN)), FixedPoint(i8::MIN));
+    }
+}
+
+#[test]
+fn test_neg() {
+    assert_eq!(-fixed!(1.5i16, 2), fixed!(-1.5i16, 2));
+    let min = FixedPoint::<i16, 2>(i16::MIN);
+    assert_eq!(min.checked_neg(), None);
+    assert_eq!(min.saturating_neg(), FixedPoint(i16::MAX));
+    assert_eq!(fixed!(-2i16, 2).checked_neg(), Some(fixed!(2i16, 2)));
+    assert_eq!(fixed!(2u16, 2).saturating_neg(), FixedPoint(0));
+}
+
+#[test]
+#[cfg(feature = "strict-arithmetic")]
+#[should_panic(expected = "fixed-point overflow")]
+fn test_strict_neg() {
+    let _ = -FixedPoint::<i16, 2>(i16::MIN);
+}
+
+#[test]
+#[cfg(not(feature = "strict-arithmetic"))]
+#[cfg_attr(debug_assertions, should_panic(expected = "overflow"))]
+fn test_overflow_neg() {
+    let negated = -FixedPoint::<i16, 2>(i16::MIN);
+    assert_eq!(negated, FixedPoint(i16::MIN));
+}
+
+#[test]
+fn test_str_literal_macro() {
+    let value: FixedPoint<i16, 2> = fixed!("1.5", 2);
+    assert_eq!(value, fixed!(1.5i16, 2));
+    assert_eq!(FixedPoint::<i32, 3>(-1250), fixed!("-1.25", 3));
+    assert_eq!(FixedPoint::<i32, 1>(20), fixed!("+2"));
+}
+
+#[test]
+fn test_div_int() {
+    assert_eq!(fixed!(3i16, 2).checked_div_int(2), Some(fixed!(1.5i16, 2)));
+    assert_eq!(fixed!(3i16, 2).checked_div_int(0), None);
+    let min = FixedPoint::<i16, 2>(i16::MIN);
+    assert_eq!(min.checked_div_int(-1), None);
+    assert_eq!(min.saturating_div_int(-1), FixedPoint(i16::MAX));
+    assert_eq!(fixed!(-3i16, 2).saturating_div_int(-2), fixed!(1.5i16, 2));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero")]
+fn test_saturating_div_int_by_zero() {
+    let _ = fixed!(1i16, 2).saturating_div_int(0);
+}
+
+#[test]
+fn test_f64() {
+    assert_eq!(FixedPoint::<i32, 2>::from_f64(1.005), Some(fixed!(1i32, 2)));
+    assert_eq!(
+        FixedPoint::<i32, 2>::from_f64(-1.255),
+        Some(fixed!(-1.25i32, 2))
+    );
+    assert_eq!(
+        FixedPoint::<i32, 2>::from_f64(2.675),
+        Some(fixed!(2.68i32, 2))
+    );
+    assert_eq!(FixedPoint::<i32, 2>::from_f64(f64::NAN), None);
+    assert_eq!(FixedPoint::<i16, 2>::from_f64(400.0), None);
+    assert_eq!(FixedPoint::<u16, 2>::from_f64(-1.0), None);
+    assert_eq!(fixed!(-1.25i32, 2).to_f64(), -1.25);
+}
+
+#[test]
+fn test_step() {
+    assert_eq!(FixedPoint::<i32, 2>::STEP.to_string(), "0.01");
+    assert_eq!(FixedPoint::<u8, 0>::STEP, FixedPoint(1));
+    assert_eq!(fixed!(1.5i32, 2) + FixedPoint::STEP, fixed!(1.51i32, 2));
+}
+
+#[test]
+fn test_quantize() {
+    let step = fixed!(0.05i32, 2);
+    assert_eq!(fixed!(0.12i32, 2).quantize(step), fixed!(0.1i32, 2));
+    assert_eq!(fixed!(0.13i32, 2).quantize(step), fixed!(0.15i32, 2));
+    assert_eq!(fixed!(-0.13i32, 2).quantize(step), fixed!(-0.15i32, 2));
+    assert_eq!(
+        fixed!(0.125i32, 3).quantize(fixed!(0.05i32, 3)),
+        fixed!(0.15i32, 3)
+    );
+    assert_eq!(
+        fixed!(0.12i32, 2).quantize(fixed!(0i32, 2)),
+        fixed!(0.12i32, 2)
+    );
+    assert_eq!(
+        FixedPoint::<u8, 0>(254).quantize(FixedPoint(10)),
+        FixedPoint(250)
+    );
+}
+
+#[test]
+fn test_write_display() {
+    let mut string = heapless::String::<8>::new();
+    fixed!(-1.25i64, 4).write_display(&mut string).unwrap();
+    assert_eq!(string, "-1.25");
+    let mut string = heapless::String::<4>::new();
+    assert!(fixed!(-1.25i64, 4).write_display(&mut string).is_err());
+}
+
+#[test]
+fn test_checked_mul_ratio() {
+    assert_eq!(
+        fixed!(10i32, 2).checked_mul_ratio(254, 100),
+        Some(fixed!(25.4i32, 2))
+    );
+    assert_eq!(
+        fixed!(0.01i32, 2).checked_mul_ratio(1, 2),
+        Some(fixed!(0.01i32, 2))
+    );
+    assert_eq!(
+        fixed!(0.01i32, 2).checked_mul_ratio(1, 3),
+        Some(fixed!(0i32, 2))
+    );
+    assert_eq!(
+        fixed!(-0.01i32, 2).checked_mul_ratio(1, 2),
+        Some(fixed!(-0.01i32, 2))
+    );
+    assert_eq!(
+        fixed!(0.05i32, 2).checked_mul_ratio(1, -10),
+        Some(fixed!(-0.01i32, 2))
+    );
+    assert_eq!(fixed!(1i32, 2).checked_mul_ratio(1, 0), None);
+    assert_eq!(FixedPoint::<u8, 0>(200).checked_mul_ratio(3, 2), None);
+    assert_eq!(
+        FixedPoint::<i8, 0>(i8::MIN).checked_mul_ratio(i8::MIN, i8::MIN),
+        Some(FixedPoint(i8::MIN))
+    );
+}
+
+#[test]
+fn test_approx() {
+    approx::assert_abs_diff_eq!(fixed!(1.23i32, 2), fixed!(1.24i32, 2));
+    approx::assert_abs_diff_ne!(fixed!(1.23i32, 2), fixed!(1.25i32, 2));
+    approx::assert_abs_diff_eq!(
+        fixed!(1.23i32, 2),
+        fixed!(1.25i32, 2),
+        epsilon = fixed!(0.02i32, 2)
+    );
+    approx::assert_relative_eq!(
+        fixed!(100i32, 2),
+        fixed!(101i32, 2),
+        max_relative = fixed!(0.01i32, 2)
+    );
+    approx::assert_relative_ne!(
+        fixed!(100i32, 2),
+        fixed!(102i32, 2),
+        max_relative = fixed!(0.01i32, 2)
+    );
+    approx::assert_abs_diff_ne!(FixedPoint::<i8, 0>(i8::MIN), FixedPoint(i8::MAX));
+}
+
+#[test]
+fn test_rust_decimal() {
+    use rust_decimal::Decimal;
+    for value in [
+        fixed!(1.5i64, 2),
+        fixed!(-123.45i64, 2),
+        FixedPoint(i64::MIN),
+    ] {
+        let decimal = Decimal::from(value);
+        assert_eq!(decimal.to_string(), value.to_fixed_string());
+        assert_eq!(FixedPoint::try_from(decimal), Ok(value));
+    }
+    let decimal = Decimal::new(15, 1);
+    assert_eq!(
+        FixedPoint::<i16, 2>::try_from(decimal),
+        Ok(fixed!(1.5i16, 2))
+    );
+    assert_eq!(
+        FixedPoint::<i16, 2>::try_from(Decimal::new(1500, 3)),
+        Ok(fixed!(1.5i16, 2))
+    );
+    let result = FixedPoint::<i16, 2>::try_from(Decimal::new(1505, 3));
+    assert_eq!(result, Err(ParseError::PrecisionLoss));
+    let result = FixedPoint::<i16, 2>::try_from(Decimal::new(400, 0));
+    assert_eq!(result, Err(ParseError::Overflow));
+
+    let decimal = Decimal::try_from(fixed!(-1.25i128, 2));
+    assert_eq!(decimal, Ok(Decimal::new(-125, 2)));
+    assert_eq!(
+        Decimal::try_from(FixedPoint::<i128, 2>(i128::MAX)),
+        Err(ParseError::Overflow)
+    );
+    assert_eq!(
+        Decimal::try_from(FixedPoint::<i128, 29>(1)),
+        Err(ParseError::Overflow)
+    );
+    assert_eq!(
+        Decimal::from(FixedPoint::<u64, 19>(u64::MAX)).mantissa(),
+        u64::MAX as i128
+    );
+}
+
+#[test]
+fn test_from_str_lenient() {
+    let cases = [
+        ("1.5", FixedPoint(150)),
+        ("  1.5\t", FixedPoint(150)),
+        ("+1.5", FixedPoint(150)),
+        ("-1.5", FixedPoint(-150)),
+        ("1_000.5", FixedPoint(100050)),
+        ("1.2_5", FixedPoint(125)),
+        (".5", FixedPoint(50)),
+        ("-.5", FixedPoint(-50)),
+        ("5.", FixedPoint(500)),
+        ("1.239", FixedPoint(123)),
+        ("-0", FixedPoint(0)),
+        ("99999999", FixedPoint(i32::MAX)),
+        (
+            "-99999999999999999999999999999999999999999",
+            FixedPoint(i32::MIN),
+        ),
+    ];
+    for (string, expected) in cases {
+        assert_eq!(
+            FixedPoint::<i32, 2>::from_str_lenient(string),
+            Ok(expected),
+            "{}",
+            string
+        );
+    }
+    let from_str_lenient = FixedPoint::<i32, 2>::from_str_lenient;
+    assert_eq!(from_str_lenient(""), Err(ParseError::Empty));
+    assert_eq!(from_str_lenient(" . "), Err(ParseError::Empty));
+    assert_eq!(from_str_lenient("1 .5"), Err(ParseError::InvalidDigit(' ')));
+    assert_eq!(from_str_lenient("-_."), Err(ParseError::Empty));
+    assert_eq!(from_str_lenient("1,5"), Err(ParseError::InvalidDigit(',')));
+    assert_eq!(
+        FixedPoint::<u8, 1>::from_str_lenient("-1"),
+        Ok(FixedPoint(0))
+    );
+}
+
+/// Algebraic identities which hold as long as no intermediate overflows
+mod identities {
+    use fixed_point::{any_fixed_point, FixedPoint};
+    use proptest::{prop_assert_eq, prop_assume, proptest};
+
+    macro_rules! identities {
+        ($($name:ident: $type:ty, $decimal:literal);+) => {
+            $(
+                mod $name {
+                    use super::*;
+
+                    type Fixed = FixedPoint<$type, $decimal>;
+
+                    proptest! {
+                        #[test]
+                        fn add_commutative(
+                            a in any_fixed_point::<$type, $decimal>(),
+                            b in any_fixed_point::<$type, $decimal>(),
+                        ) {
+                            prop_assume!(a.0.checked_add(b.0).is_some());
+                            prop_assert_eq!(a + b, b + a);
+                        }
+
+                        #[test]
+                        fn add_zero(a in any_fixed_point::<$type, $decimal>()) {
+                            prop_assert_eq!(a + Fixed::default(), a);
+                        }
+
+                        #[test]
+                        fn mul_one(raw in <$type>::MIN / Fixed::EXP_T..=<$type>::MAX / Fixed::EXP_T) {
+                            let a = Fixed::from_raw(raw);
+                            prop_assert_eq!(a * Fixed::new(1, 0), a);
+                        }
+                    }
+                }
+            )+
+        };
+    }
+
+    identities!(signed: i32, 3; unsigned: u16, 2; wide: i64, 6);
+
+    proptest! {
+        #[test]
+        fn neg_involutive(a in any_fixed_point::<i32, 3>()) {
+            prop_assume!(a.0 != i32::MIN);
+            prop_assert_eq!(-(-a), a);
+        }
+
+        #[test]
+        fn add_neg_zero(a in any_fixed_point::<i64, 6>()) {
+            prop_assume!(a.0 != i64::MIN);
+            prop_assert_eq!(a + -a, FixedPoint::default());
+        }
+    }
+}
+
+#[test]
+fn test_any_fixed() {
+    let mut values: Vec<AnyFixed<i32>> = vec![
+        fixed!(1.5i32, 1).into(),
+        fixed!(-0.125i32, 3).into(),
+        fixed!(1.499i32, 3).into(),
+        fixed!(-0.1i32, 1).into(),
+        fixed!(0.001i32, 3).into(),
+    ];
+    values.sort();
+    let strings: Vec<_> = values.iter().map(ToString::to_string).collect();
+    assert_eq!(strings, ["-0.125", "-0.1", "0.001", "1.499", "1.5"]);
+    assert_eq!(
+        AnyFixed::from(fixed!(1i32, 1)),
+        AnyFixed::from(fixed!(1i32, 3))
+    );
+    assert_eq!(FixedPoint::try_from(values[4]), Ok(fixed!(1.5i32, 2)));
+    let result = FixedPoint::<i32, 2>::try_from(values[3]);
+    assert_eq!(result, Err(ParseError::PrecisionLoss));
+    let value = AnyFixed::from(FixedPoint::<i8, 0>(i8::MIN));
+    assert_eq!(value.to_string(), "-128.0");
+    assert!(value < AnyFixed::from(FixedPoint::<i8, 2>(i8::MIN)));
+    for value in [fixed!(-1.255i32, 3), fixed!(0.5i32, 3), fixed!(7i32, 3)] {
+        let any = AnyFixed::from(value);
+        assert_eq!(format!("{:.2}", any), format!("{:.2}", value));
+        assert_eq!(format!("{:#}", any), format!("{:#}", value));
+        assert_eq!(format!("{:.5}", any), format!("{:.5}", value));
+    }
+}
+
+#[test]
+fn test_wrapping_from_f32() {
+    assert_eq!(FixedPoint::<u8, 2>::wrapping_from_f32(1.5), FixedPoint(150));
+    assert_eq!(FixedPoint::<u8, 2>::wrapping_from_f32(2.56), FixedPoint(0));
+    assert_eq!(FixedPoint::<u8, 2>::wrapping_from_f32(2.6), FixedPoint(4));
+    assert_eq!(
+        FixedPoint::<i8, 1>::wrapping_from_f32(-12.9),
+        FixedPoint(127)
+    );
+    assert_eq!(
+        FixedPoint::<i16, 2>::wrapping_from_f32(f32::NAN),
+        FixedPoint(0)
+    );
+}
+
+#[test]
+fn test_to_scaled_int() {
+    assert_eq!(fixed!(1.2345i32, 4).to_scaled_int(2), Some(123));
+    assert_eq!(fixed!(1.235i32, 4).to_scaled_int(2), Some(124));
+    assert_eq!(fixed!(-1.235i32, 4).to_scaled_int(2), Some(-124));
+    assert_eq!(fixed!(1.2345i32, 4).to_scaled_int(4), Some(12345));
+    assert_eq!(fixed!(1.5i32, 1).to_scaled_int(3), Some(1500));
+    assert_eq!(fixed!(4.5i16, 1).to_scaled_int(4), None);
+    assert_eq!(fixed!(1i8, 0).to_scaled_int(5), None);
+}
+
+#[test]
+fn test_clamp_magnitude() {
+    let max = fixed!(2i32, 1);
+    assert_eq!(fixed!(-3i32, 1).clamp_magnitude(max), fixed!(-2i32, 1));
+    assert_eq!(fixed!(3i32, 1).clamp_magnitude(max), fixed!(2i32, 1));
+    assert_eq!(fixed!(-1.5i32, 1).clamp_magnitude(max), fixed!(-1.5i32, 1));
+    assert_eq!(fixed!(1.5i32, 1).clamp_magnitude(max), fixed!(1.5i32, 1));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Negative magnitude")]
+fn test_clamp_negative_magnitude() {
+    let _ = fixed!(1i32, 1).clamp_magnitude(fixed!(-1i32, 1));
+}
+
+#[test]
+fn test_common_constants() {
+    assert_eq!(FixedPoint::<i32, 1>::half().to_string(), "0.5");
+    assert_eq!(FixedPoint::<i32, 4>::half().to_string(), "0.5");
+    assert_eq!(FixedPoint::<u8, 2>::half(), fixed!(0.5u8, 2));
+    assert_eq!(FixedPoint::<i32, 2>::one(), fixed!(1i32, 2));
+    assert_eq!(FixedPoint::<i32, 0>::one(), FixedPoint(1));
+    assert_eq!(FixedPoint::<i16, 3>::ten(), fixed!(10i16, 3));
+}
+
+#[test]
+fn test_checked_round_to() {
+    let max = FixedPoint::<i16, 2>(i16::MAX);
+    assert_eq!(max.checked_round_to(1), None);
+    assert_eq!(FixedPoint::<i16, 2>(i16::MIN).checked_round_to(1), None);
+    assert_eq!(max.checked_round_to(2), Some(max));
+    assert_eq!(
+        FixedPoint::<i16, 2>(32764).checked_round_to(1),
+        Some(FixedPoint(32760))
+    );
+    assert_eq!(
+        fixed!(2.5i32, 2).checked_round_to(0),
+        Some(fixed!(3.0i32, 2))
+    );
+}
+
+#[test]
+#[should_panic(expected = "fixed-point overflow")]
+fn test_round_to_overflow() {
+    let _ = FixedPoint::<i16, 2>(i16::MAX).round_to(1);
+}
+
+#[test]
+fn test_round_slice_to() {
+    let mut values = [
+        fixed!(0.5i32, 1),
+        fixed!(1.5i32, 1),
+        fixed!(2.5i32, 1),
+        fixed!(-2.5i32, 1),
+    ];
+    FixedPoint::round_slice_to(&mut values, 0);
+    assert_eq!(
+        values,
+        [
+            fixed!(0i32, 1),
+            fixed!(2i32, 1),
+            fixed!(2i32, 1),
+            fixed!(-2i32, 1)
+        ]
+    );
+    let mut values = [fixed!(1.25i32, 3), fixed!(1.35i32, 3), fixed!(1.251i32, 3)];
+    FixedPoint::round_slice_to(&mut values, 1);
+    assert_eq!(
+        values,
+        [fixed!(1.2i32, 3), fixed!(1.4i32, 3), fixed!(1.3i32, 3)]
+    );
+}
+
+#[test]
+fn test_try_from_str() {
+    assert_eq!(FixedPoint::try_from("1.5"), Ok(fixed!(1.5i32, 2)));
+    assert_eq!(
+        FixedPoint::<i32, 2>::try_from("1.x"),
+        Err(ParseError::InvalidDigit('x'))
+    );
+}
+
+#[test]
+fn test_format_min() {
+    assert_eq!(FixedPoint::<i16, 2>(i16::MIN).to_string(), "-327.68");
+    assert_eq!(FixedPoint::<i32, 2>(i32::MIN).to_string(), "-21474836.48");
+    assert_eq!(FixedPoint::<i32, 9>(i32::MIN).to_string(), "-2.147483648");
+    assert_eq!(FixedPoint::<i32, 0>(i32::MIN).to_string(), "-2147483648.0");
+    assert_eq!(format!("{:.1}", FixedPoint::<i16, 2>(i16::MIN)), "-327.7");
+    assert_eq!(
+        FixedPoint::<i128, 38>(i128::MIN).to_string(),
+        "-1.70141183460469231731687303715884105728"
+    );
+}
+
+#[test]
+fn test_from_ascii() {
+    assert_eq!(FixedPoint::from_ascii(b"1.5"), Ok(fixed!(1.5i32, 2)));
+    assert_eq!(FixedPoint::from_ascii(b"-0.25"), Ok(fixed!(-0.25i32, 2)));
+    let from_ascii = FixedPoint::<i32, 2>::from_ascii;
+    assert_eq!(from_ascii(b"1.x"), Err(ParseError::InvalidDigit('x')));
+    let result = from_ascii(&[b'1', b'.', 0xc3, 0xa9]);
+    assert_eq!(
+        result,
+        Err(ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER))
+    );
+    let result = from_ascii(&[b'1', 0xff]);
+    assert_eq!(
+        result,
+        Err(ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER))
+    );
+    assert_eq!(from_ascii(b""), Err(ParseError::Empty));
+}
+
+#[test]
+fn test_int_add_sub() {
+    assert_eq!(
+        fixed!(1.5i16, 2).checked_add_int(2),
+        Some(fixed!(3.5i16, 2))
+    );
+    assert_eq!(
+        fixed!(1.5i16, 2).checked_sub_int(2),
+        Some(fixed!(-0.5i16, 2))
+    );
+    assert_eq!(fixed!(300i16, 2).checked_add_int(30), None);
+    assert_eq!(fixed!(1i16, 2).checked_add_int(1000), None);
+    assert_eq!(
+        fixed!(300i16, 2).saturating_add_int(30),
+        FixedPoint(i16::MAX)
+    );
+    assert_eq!(
+        fixed!(-300i16, 2).saturating_add_int(-30),
+        FixedPoint(i16::MIN)
+    );
+    assert_eq!(
+        fixed!(-300i16, 2).saturating_sub_int(30),
+        FixedPoint(i16::MIN)
+    );
+    assert_eq!(
+        fixed!(300i16, 2).saturating_sub_int(-30),
+        FixedPoint(i16::MAX)
+    );
+    assert_eq!(fixed!(1u16, 2).saturating_sub_int(2), FixedPoint(0));
+}
+
+#[test]
+fn test_percent_change() {
+    assert_eq!(
+        fixed!(1.5i32, 2).percent_change(fixed!(1.2i32, 2)),
+        Some(fixed!(25i32, 2))
+    );
+    assert_eq!(
+        fixed!(0.9i32, 2).percent_change(fixed!(1.2i32, 2)),
+        Some(fixed!(-25i32, 2))
+    );
+    assert_eq!(
+        fixed!(1i32, 2).percent_change(fixed!(3i32, 2)),
+        Some(fixed!(-66.66i32, 2))
+    );
+    assert_eq!(fixed!(1i32, 2).percent_change(fixed!(0i32, 2)), None);
+    assert_eq!(fixed!(1u16, 2).percent_change(fixed!(2u16, 2)), None);
+    assert_eq!(fixed!(327i16, 2).percent_change(fixed!(0.01i16, 2)), None);
+}
+
+#[test]
+fn test_to_grouped_string() {
+    assert_eq!(
+        fixed!(1234567.5i64, 2).to_grouped_string(','),
+        "1,234,567.5"
+    );
+    assert_eq!(
+        fixed!(-1234567.5i64, 2).to_grouped_string(','),
+        "-1,234,567.5"
+    );
+    assert_eq!(fixed!(123456i64, 2).to_grouped_string('_'), "123_456.0");
+    assert_eq!(fixed!(-999.25i64, 2).to_grouped_string(','), "-999.25");
+    assert_eq!(fixed!(1000i64, 0).to_grouped_string(' '), "1 000.0");
+    assert_eq!(fixed!(0.125i64, 3).to_grouped_string(','), "0.125");
+}
+
+#[test]
+fn test_sum_ref() {
+    let values = [fixed!(1.5i32, 2), fixed!(2.25i32, 2), fixed!(-0.75i32, 2)];
+    let sum: FixedPoint<i32, 2> = values.iter().sum();
+    assert_eq!(sum, fixed!(3i32, 2));
+    assert_eq!([].iter().sum::<FixedPoint<i32, 2>>(), FixedPoint(0));
+}
+
+#[test]
+fn test_to_higher() {
+    assert_eq!(fixed!(1.5i32, 1).to_higher::<3>(), fixed!(1.5i32, 3));
+    assert_eq!(fixed!(1.5i32, 1).to_higher::<1>(), fixed!(1.5i32, 1));
+}
+
+#[test]
+fn test_euclid() {
+    let full = fixed!(360i32, 1);
+    assert_eq!(fixed!(-10i32, 1).rem_euclid(full), fixed!(350i32, 1));
+    assert_eq!(fixed!(-10i32, 1).div_euclid(full), -1);
+    assert_eq!(fixed!(-730.5i32, 1).rem_euclid(full), fixed!(349.5i32, 1));
+    assert_eq!(fixed!(-730.5i32, 1).div_euclid(full), -3);
+    assert_eq!(fixed!(370i32, 1).rem_euclid(full), fixed!(10i32, 1));
+    assert_eq!(fixed!(-10i32, 1).rem_euclid(-full), fixed!(350i32, 1));
+    assert_eq!(fixed!(-10i32, 1).div_euclid(-full), 1);
+    let (a, b) = (fixed!(-7.5i32, 1), fixed!(2i32, 1));
+    assert_eq!(b * a.div_euclid(b) + a.rem_euclid(b), a);
+}
+
+#[test]
+fn test_checked_rescale_round() {
+    use fixed_point::RoundingMode::*;
+    let value = fixed!(1.25i32, 2);
+    assert_eq!(
+        value.checked_rescale_round::<1>(Truncate),
+        Some(fixed!(1.2i32, 1))
+    );
+    assert_eq!(
+        value.checked_rescale_round::<1>(HalfAwayFromZero),
+        Some(fixed!(1.3i32, 1))
+    );
+    assert_eq!(
+        value.checked_rescale_round::<1>(HalfEven),
+        Some(fixed!(1.2i32, 1))
+    );
+    let value = fixed!(-1.25i32, 2);
+    assert_eq!(
+        value.checked_rescale_round::<1>(HalfAwayFromZero),
+        Some(fixed!(-1.3i32, 1))
+    );
+    assert_eq!(
+        value.checked_rescale_round::<3>(HalfEven),
+        Some(fixed!(-1.25i32, 3))
+    );
+    let value = fixed!(400i16, 0);
+    assert_eq!(value.checked_rescale_round::<2>(Truncate), None);
+    assert_eq!(
+        value.checked_rescale_round::<1>(Truncate),
+        Some(fixed!(400i16, 1))
+    );
+}
+
+#[test]
+fn test_is_signed() {
+    assert!(!FixedPoint::<u16, 2>::is_signed());
+    assert!(FixedPoint::<i16, 2>::is_signed());
+    assert!(FixedPoint::<i128, 2>::is_signed());
+    assert!(!FixedPoint::<usize, 0>::is_signed());
+}
+
+#[test]
+fn test_number_bounds() {
+    use fixed_point::Number;
+    assert_eq!(<u8 as Number>::min_value(), 0);
+    assert_eq!(<u8 as Number>::max_value(), 255);
+    assert_eq!(<i16 as Number>::min_value(), -32768);
+    assert_eq!(<i16 as Number>::max_value(), 32767);
+    assert_eq!(<i128 as Number>::max_value(), i128::MAX);
+}
+
+#[test]
+fn test_dot() {
+    let a = [fixed!(1.5i16, 2), fixed!(-2.0i16, 2), fixed!(300i16, 2)];
+    let b = [fixed!(2.0i16, 2), fixed!(0.25i16, 2), fixed!(100i16, 2)];
+    // 3.0 - 0.5 + 30000.0, out of range of i16 but not i32
+    assert_eq!(FixedPoint::dot(&a, &b), Some(fixed!(30002.5i32, 2)));
+    assert_eq!(FixedPoint::dot(&a[..1], &b[..1]), Some(fixed!(3.0i32, 2)));
+    assert_eq!(FixedPoint::<i16, 2>::dot(&[], &[]), Some(fixed!(0i32, 2)));
+    assert_eq!(FixedPoint::dot(&a, &b[..2]), None);
+    let max = [FixedPoint::<u8, 0>(255); 2];
+    assert_eq!(FixedPoint::dot(&max, &max), None);
+}
+
+#[test]
+fn test_into_f32() {
+    assert_eq!(f32::from(fixed!(-1.25i16, 2)), -1.25);
+    assert_eq!(f32::from(fixed!(2.5u8, 1)), 2.5);
+    let value: f32 = fixed!(0.125i32, 3).into();
+    assert_eq!(value, 0.125);
+}
+
+#[test]
+fn test_saturating_to_scaled_int() {
+    assert_eq!(fixed!(1.2345i32, 4).saturating_to_scaled_int(2), 123);
+    assert_eq!(fixed!(-1.2345i32, 4).saturating_to_scaled_int(2), -123);
+    assert_eq!(fixed!(300i16, 1).saturating_to_scaled_int(3), i16::MAX);
+    assert_eq!(fixed!(-300i16, 1).saturating_to_scaled_int(3), i16::MIN);
+    assert_eq!(fixed!(3u8, 0).saturating_to_scaled_int(100), u8::MAX);
+    assert_eq!(fixed!(0u8, 0).saturating_to_scaled_int(100), 0);
+}
+
+#[test]
+fn test_constructors_consistent() {
+    type F = FixedPoint<i32, 3>;
+    let cases = [
+        (fixed!(1.5i32, 3), F::new(15, 1), "1.5"),
+        (fixed!(-1.5i32, 3), F::new(-15, 1), "-1.5"),
+        (fixed!(12.345i32, 3), F::new(12345, 3), "12.345"),
+        (fixed!(7i32, 3), F::new(7, 0), "7"),
+        (fixed!(0.001i32, 3), F::new(1, 3), "0.001"),
+        (fixed!(0.123i32, 3), F::new(123456, 6), "0.123456"),
+    ];
+    for (expected, new, string) in cases {
+        assert_eq!(new.0, expected.0, "{}", string);
+        assert_eq!(string.parse::<F>().unwrap().0, expected.0, "{}", string);
+        assert_eq!(
+            F::from_str_lenient(string).unwrap().0,
+            expected.0,
+            "{}",
+            string
+        );
+    }
+    assert_eq!(F::try_new(123, 2), Some(fixed!(1.23i32, 3)));
+    assert_eq!(FixedPoint::<i8, 1>::try_new(13, 0), None);
+    assert_eq!(
+        <i16 as fixed_point::Number>::from_parts(3, 25, 2, 3),
+        Some(3250)
+    );
+    assert_eq!(<i16 as fixed_point::Number>::from_parts(33, 0, 0, 3), None);
+}
+
+#[test]
+fn test_debug_verbose() {
+    let verbose = fixed!(1.5i32, 2).debug_verbose();
+    assert_eq!(format!("{}", verbose), "1.50 (raw=150, D=2)");
+    let verbose = fixed!(-0.05i16, 3).debug_verbose();
+    assert_eq!(format!("{}", verbose), "-0.050 (raw=-50, D=3)");
+    assert_eq!(
+        format!("{}", fixed!(7u8, 0).debug_verbose()),
+        "7 (raw=7, D=0)"
+    );
+}
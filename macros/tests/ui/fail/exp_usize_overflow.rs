@@ -0,0 +1,5 @@
+use fixed_point::FixedPoint;
+
+fn main() {
+    let _ = FixedPoint::<u128, 20>(1).exp();
+}
@@ -0,0 +1,6 @@
+use fixed_point::{fixed, FixedPoint};
+
+fn main() {
+    let _: FixedPoint<i32, 2> = fixed!("1.x5", 2);
+    let _: FixedPoint<i32, 2> = fixed!("1.", 2);
+}
@@ -0,0 +1,10 @@
+use fixed_point::{FixedPoint, Quantity};
+
+struct Meter;
+struct Second;
+
+fn main() {
+    let distance = Quantity::<i32, 2, Meter>::new(FixedPoint(150));
+    let duration = Quantity::<i32, 2, Second>::new(FixedPoint(200));
+    let _ = distance + duration;
+}
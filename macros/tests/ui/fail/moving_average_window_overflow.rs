@@ -0,0 +1,5 @@
+use fixed_point::MovingAverage;
+
+fn main() {
+    let _ = MovingAverage::<u8, 0, 257>::new();
+}
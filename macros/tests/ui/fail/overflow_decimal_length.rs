@@ -0,0 +1,5 @@
+use fixed_point::FixedPoint;
+
+fn main() {
+    let _ = FixedPoint::<u8, 10>::new(1, 0);
+}
@@ -0,0 +1,5 @@
+use fixed_point::FixedPoint;
+
+fn main() {
+    let _ = FixedPoint::<i32, 2>(150).to_higher::<1>();
+}
@@ -0,0 +1,9 @@
+#![deny(unused_must_use)]
+
+use fixed_point::FixedPoint;
+
+fn main() {
+    let value = FixedPoint::<i32, 1>(15);
+    value.rescale::<2>();
+    value.checked_rem(FixedPoint(10));
+}
@@ -0,0 +1,9 @@
+use fixed_point::{FixedPoint, Quantity};
+
+struct Meter;
+
+fn main() {
+    let a = Quantity::<i32, 2, Meter>::new(FixedPoint(150));
+    let b = Quantity::<i32, 2, Meter>::new(FixedPoint(200));
+    assert_eq!((a + b).value(), FixedPoint(350));
+}
@@ -23,39 +23,206 @@
 //!   use fixed_point::fixed;
 //!   let decimal = fixed!(-1.1i16);
 //!   ```
+//!
+//! * Define a fixed-point variable with hexadecimal, octal or binary integer literal,
+//!   note that radix float literals like `0x10.5` are rejected by rust lexer,
+//!   so fractional part is only available in decimal
+//!
+//!   ```
+//!   use fixed_point::fixed;
+//!   let decimal = fixed!(0x10u16, 2);
+//!   ```
+//!
+//! * Define a fixed-point variable from string literal, validated at compile time
+//!
+//!   ```
+//!   use fixed_point::{fixed, FixedPoint};
+//!   let decimal: FixedPoint<i16, 2> = fixed!("-1.5", 2);
+//!   ```
+//!
+//! ## Decimal length
+//!
+//! `D` must satisfy `10^D <= T::MAX`, i.e. `D <= 2` for `u8`/`i8`, `D <= 4` for `u16`/`i16`,
+//! `D <= 9` for `u32`/`i32`, `D <= 18` for `i64`, `D <= 19` for `u64` and `D <= 38` for
+//! `u128`/`i128`. Methods depending on `10^D` fail to compile with larger `D`.
+//!
+//! ## Overflow
+//!
+//! Arithmetic operators behave like those of backing type, i.e. panic on overflow
+//! in debug mode and wrap in release mode. Enable `strict-arithmetic` feature to
+//! always panic with `fixed-point overflow`, or use `checked_*` and `saturating_*`
+//! methods to handle overflow explicitly.
 
 #![cfg_attr(not(any(test, feature = "std")), no_std)]
 
 /// Define a fixed-point number
 pub use macros::fixed;
 
-use core::{convert, fmt::Display, ops, str::FromStr};
-#[cfg(all(feature = "serde", not(any(test, feature = "std"))))]
+use core::{convert, fmt::Display, iter, marker::PhantomData, ops, str::FromStr};
 use num_traits::float::FloatCore;
-use num_traits::pow::Pow;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, PrimInt, SaturatingAdd,
+    ToPrimitive, WrappingAdd, WrappingMul,
+};
+
+/// Assert that two fixed-point numbers differ by no more than tolerance
+///
+/// ```
+/// use fixed_point::{assert_fixed_approx_eq, FixedPoint};
+/// assert_fixed_approx_eq!(FixedPoint::<i32, 2>(100), FixedPoint(101), FixedPoint(1));
+/// ```
+#[macro_export]
+macro_rules! assert_fixed_approx_eq {
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, &$tolerance) {
+            (left, right, tolerance) => {
+                let difference = if *left > *right { *left - *right } else { *right - *left };
+                if difference > *tolerance {
+                    panic!(
+                        "assertion `left ~= right` failed\n     left: {}\n    right: {}\ntolerance: {}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
 
-#[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FixedPoint<T, const D: u8>(pub T);
 
 impl<T, const D: u8> FixedPoint<T, D> {
+    #[must_use]
     pub fn decimal_length(self) -> u8 {
         D
     }
 
+    /// `10^D` as `usize`, fails to compile if it doesn't fit, see `exp_t` otherwise
+    #[must_use]
     pub fn exp(self) -> usize {
-        10_usize.pow(D as u32)
+        const {
+            let max = <usize as Number>::MAX_DECIMAL_LENGTH;
+            assert!(D <= max, "Decimal length D exceeds range of usize")
+        };
+        <usize as Number>::POWERS_OF_TEN[D as usize]
+    }
+
+    /// Construct from raw value in units of `10^-D`
+    #[must_use]
+    pub const fn from_raw(raw: T) -> Self {
+        Self(raw)
+    }
+
+    /// Raw value in units of `10^-D`
+    #[must_use]
+    pub fn raw(self) -> T {
+        self.0
+    }
+
+    /// Alias of `from_raw` following naming of `fixed` crate
+    #[must_use]
+    pub const fn from_bits(bits: T) -> Self {
+        Self::from_raw(bits)
+    }
+
+    /// Alias of `raw` following naming of `fixed` crate
+    #[must_use]
+    pub fn to_bits(self) -> T {
+        self.raw()
+    }
+
+    /// Construct from `units` already scaled to `10^-D`, e.g. `1500` with `D = 3` is `1.5`
+    #[must_use]
+    pub const fn from_scaled_units(units: T) -> Self {
+        Self(units)
+    }
+}
+
+impl<T> FixedPoint<T, 3> {
+    /// Construct from thousandths
+    #[must_use]
+    pub const fn from_millis(millis: T) -> Self {
+        Self(millis)
+    }
+}
+
+impl<T> FixedPoint<T, 6> {
+    /// Construct from millionths
+    #[must_use]
+    pub const fn from_micros(micros: T) -> Self {
+        Self(micros)
+    }
+}
+
+impl<T: Clone, const D: u8> FixedPoint<T, D> {
+    /// Create an array with every element set to `value`
+    #[must_use]
+    pub fn filled<const N: usize>(value: Self) -> [Self; N] {
+        core::array::from_fn(|_| value.clone())
     }
 }
 
-pub trait Number {
+impl<T: Ord, const D: u8> FixedPoint<T, D> {
+    pub fn sort_ascending(slice: &mut [Self]) {
+        slice.sort_unstable()
+    }
+
+    pub fn sort_descending(slice: &mut [Self]) {
+        slice.sort_unstable_by(|a, b| b.cmp(a))
+    }
+}
+
+/// `10^n`, returns `None` if it overflows `u128`
+#[must_use]
+pub const fn pow10(n: u32) -> Option<u128> {
+    10_u128.checked_pow(n)
+}
+
+pub trait Number: Sized + 'static {
+    /// Largest decimal length `D` such that `10^D` is representable
+    const MAX_DECIMAL_LENGTH: u8;
+    /// `10^n` for every `n` up to `MAX_DECIMAL_LENGTH`
+    const POWERS_OF_TEN: &'static [Self];
+    /// Whether negative values are representable
+    const SIGNED: bool;
+
     fn ten() -> Self;
     fn zero() -> Self;
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+
+    /// Raw value of `integer` and `fraction` with `digits` fractional digits at `decimal_length`,
+    /// i.e. `integer * 10^decimal_length + fraction * 10^(decimal_length - digits)`,
+    /// fractional digits beyond `decimal_length` are truncated.
+    ///
+    /// Both parts are expected to have the same sign, returns `None` on overflow
+    fn from_parts(integer: Self, fraction: Self, digits: u8, decimal_length: u8) -> Option<Self>;
+
+    /// `self * rhs / div` truncating towards zero with a widened intermediate,
+    /// returns `None` if `div` is zero or result out of range.
+    ///
+    /// There's no wider type for `u128` and `i128`, so `None` is also returned
+    /// if `self * rhs` overflows for them
+    fn checked_mul_div(self, rhs: Self, div: Self) -> Option<Self>;
 }
 
 macro_rules! impl_number {
-    ($($types:ty),+) => {
+    ($($types:ty => $wide:ty),+) => {
         $(
             impl Number for $types {
+                const MAX_DECIMAL_LENGTH: u8 = <$types>::MAX.ilog10() as u8;
+                const POWERS_OF_TEN: &'static [Self] = &{
+                    const N: usize = <$types>::MAX.ilog10() as usize + 1;
+                    let mut powers = [1; N];
+                    let mut i = 1;
+                    while i < N {
+                        powers[i] = pow10(i as u32).unwrap() as $types;
+                        i += 1;
+                    }
+                    powers
+                };
+                const SIGNED: bool = <$types>::MIN != 0;
+
                 fn ten() -> Self {
                     10
                 }
@@ -63,119 +230,2064 @@ macro_rules! impl_number {
                 fn zero() -> Self {
                     0
                 }
+
+                fn min_value() -> Self {
+                    <$types>::MIN
+                }
+
+                fn max_value() -> Self {
+                    <$types>::MAX
+                }
+
+                fn from_parts(integer: Self, fraction: Self, digits: u8, decimal_length: u8) -> Option<Self> {
+                    let exp = |n: u8| Self::POWERS_OF_TEN.get(n as usize).copied();
+                    let fraction = if digits <= decimal_length {
+                        fraction.checked_mul(exp(decimal_length - digits)?)?
+                    } else {
+                        exp(digits - decimal_length).map_or(0, |exp| fraction / exp)
+                    };
+                    integer.checked_mul(exp(decimal_length)?)?.checked_add(fraction)
+                }
+
+                fn checked_mul_div(self, rhs: Self, div: Self) -> Option<Self> {
+                    let product = (self as $wide).checked_mul(rhs as $wide)?;
+                    let quotient = product.checked_div(div as $wide)?;
+                    Self::try_from(quotient).ok()
+                }
+            }
+        )+
+    };
+}
+
+impl_number!(u8 => u16, i8 => i16, u16 => u32, i16 => i32, u32 => u64, i32 => i64);
+impl_number!(u64 => u128, i64 => i128, u128 => u128, i128 => i128);
+impl_number!(usize => u128, isize => i128);
+
+macro_rules! impl_const_cmp {
+    ($($types:ty),+) => {
+        $(
+            impl<const D: u8> FixedPoint<$types, D> {
+                /// Compare raw values, usable in const context
+                #[must_use]
+                pub const fn const_cmp(self, other: Self) -> core::cmp::Ordering {
+                    if self.0 < other.0 {
+                        core::cmp::Ordering::Less
+                    } else if self.0 > other.0 {
+                        core::cmp::Ordering::Greater
+                    } else {
+                        core::cmp::Ordering::Equal
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_const_cmp!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Integer type twice as wide for intermediate results
+pub trait Widen: Into<Self::Wide> + convert::TryFrom<Self::Wide> {
+    type Wide: PrimInt;
+}
+
+macro_rules! impl_widen {
+    ($($types:ty => $wide:ty),+) => {
+        $(
+            impl Widen for $types {
+                type Wide = $wide;
+            }
+        )+
+    };
+}
+
+impl_widen!(u8 => u16, i8 => i16, u16 => u32, i16 => i32, u32 => u64, i32 => i64);
+impl_widen!(u64 => u128, i64 => i128);
+
+/// Signed integer type twice as wide, holds difference of any two values
+pub trait SignedWiden: Into<Self::SignedWide> {
+    type SignedWide: PrimInt;
+}
+
+macro_rules! impl_signed_widen {
+    ($($types:ty => $wide:ty),+) => {
+        $(
+            impl SignedWiden for $types {
+                type SignedWide = $wide;
             }
         )+
     };
 }
 
-impl_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+impl_signed_widen!(u8 => i16, i8 => i16, u16 => i32, i16 => i32, u32 => i64, i32 => i64);
+impl_signed_widen!(u64 => i128, i64 => i128);
+
+impl<T: Number, const D: u8> FixedPoint<T, D> {
+    const VALID_DECIMAL_LENGTH: () = assert!(
+        D <= T::MAX_DECIMAL_LENGTH,
+        "Decimal length D exceeds range of backing type"
+    );
+}
+
+impl<T: Copy + Number, const D: u8> FixedPoint<T, D> {
+    /// `10^D` in backing type
+    pub const EXP_T: T = {
+        let () = Self::VALID_DECIMAL_LENGTH;
+        T::POWERS_OF_TEN[D as usize]
+    };
+
+    /// Smallest representable increment `10^-D`, i.e. raw value `1`
+    pub const STEP: Self = Self(T::POWERS_OF_TEN[0]);
+
+    /// `10^D` in backing type, same as `EXP_T`
+    #[must_use]
+    pub fn exp_t(self) -> T {
+        Self::EXP_T
+    }
+
+    /// Whether backing type `T` is signed
+    #[must_use]
+    pub const fn is_signed() -> bool {
+        T::SIGNED
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + ops::Add<Output = T> + ops::Mul<Output = T> + ops::Div<Output = T>,
+{
+    /// `1.0`
+    #[must_use]
+    pub fn one() -> Self {
+        Self(Self::EXP_T)
+    }
+
+    /// `0.5`, fails to compile if `D` is zero
+    #[must_use]
+    pub fn half() -> Self {
+        const { assert!(D > 0, "Half is not representable with zero decimal length") };
+        let one = T::POWERS_OF_TEN[0];
+        Self(Self::EXP_T / (one + one))
+    }
+
+    /// `10.0`, overflows if `D` is `T::MAX_DECIMAL_LENGTH`
+    #[must_use]
+    pub fn ten() -> Self {
+        Self(Self::EXP_T * T::ten())
+    }
+}
 
 impl<T, const D: u8> FixedPoint<T, D>
 where
-    T: Number + Pow<u8, Output = T> + ops::Mul<Output = T> + ops::Add<Output = T>,
+    T: Copy + Number + ops::Mul<Output = T> + ops::Div<Output = T>,
 {
+    /// Construct from `number` with `decimal` fractional digits,
+    /// e.g. `new(11, 1)` is `1.1`.
+    ///
+    /// Digits beyond `D` are truncated, so `new(1234, 4)` with `D = 2` is `0.12`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if scaling `number` up to `D` digits overflows.
+    #[must_use]
     pub fn new(number: T, decimal: u8) -> Self {
-        Self(number * T::ten().pow(D - decimal))
+        let () = Self::VALID_DECIMAL_LENGTH;
+        let raw = T::from_parts(T::zero(), number, decimal, D);
+        Self(raw.expect("fixed-point overflow"))
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedMul,
+{
+    /// Same as `new`, returns `None` if `decimal > D` or scaling overflows
+    #[must_use]
+    pub fn try_new(number: T, decimal: u8) -> Option<Self> {
+        let () = Self::VALID_DECIMAL_LENGTH;
+        if decimal > D {
+            return None;
+        }
+        T::from_parts(T::zero(), number, decimal, D).map(Self)
     }
 }
 
 impl<T, const D: u8> FixedPoint<T, D>
 where
-    T: Copy + Number + Pow<u32, Output = T> + ops::Div<Output = T> + ops::Rem<Output = T>,
+    T: Copy + Number + ops::Div<Output = T> + ops::Rem<Output = T>,
 {
+    /// Integer part truncated toward zero.
+    ///
+    /// Fails to compile if `10^D` overflows `T`, see [decimal length](crate#decimal-length)
+    #[must_use]
     pub fn integer(&self) -> T {
-        self.0 / (T::ten()).pow(D as u32)
+        let () = Self::VALID_DECIMAL_LENGTH;
+        self.0 / Self::EXP_T
     }
 
+    /// Fractional part in units of `10^-D`, carries sign of value.
+    ///
+    /// Fails to compile if `10^D` overflows `T`, see [decimal length](crate#decimal-length)
+    #[must_use]
     pub fn decimal(&self) -> T {
-        self.0 % (T::ten()).pow(D as u32)
+        let () = Self::VALID_DECIMAL_LENGTH;
+        self.0 % Self::EXP_T
     }
 }
 
-impl<T: ops::Div<Output = T>, const D: u8> ops::Div<T> for FixedPoint<T, D> {
-    type Output = Self;
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + PartialEq + ops::Div<Output = T> + ops::Rem<Output = T>,
+{
+    /// Whether fractional part is zero
+    #[must_use]
+    pub fn is_integer(self) -> bool {
+        self.decimal() == T::zero()
+    }
 
-    fn div(self, div: T) -> Self {
-        Self(self.0 / div)
+    /// Whether value is `10^n` for some integer `n`, e.g. `100.0` or `0.01`
+    #[must_use]
+    pub fn is_power_of_ten(self) -> bool {
+        T::POWERS_OF_TEN.contains(&self.0)
+    }
+
+    /// Number of fractional digits after trimming trailing zeros
+    #[must_use]
+    pub fn fractional_digits(self) -> u8 {
+        let mut decimal = self.decimal();
+        if decimal == T::zero() {
+            return 0;
+        }
+        let mut length = D;
+        while decimal % T::ten() == T::zero() {
+            decimal = decimal / T::ten();
+            length -= 1;
+        }
+        length
+    }
+
+    /// Raw value and decimal length with trailing fractional zeros removed,
+    /// e.g. `1.100` is `(11, 1)`
+    #[must_use]
+    pub fn normalize(self) -> (T, u8) {
+        let length = self.fractional_digits();
+        (self.0 / T::POWERS_OF_TEN[(D - length) as usize], length)
     }
 }
 
-impl<T: Copy + Into<i32>, const D: u8> Into<f32> for FixedPoint<T, D> {
-    fn into(self) -> f32 {
-        let value: i32 = self.0.into();
-        value as f32 / self.exp() as f32
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + ToPrimitive + ops::Div<Output = T> + ops::Rem<Output = T>,
+{
+    /// Integer part truncated towards zero, returns `None` if it doesn't fit in `i32`
+    #[must_use]
+    pub fn try_to_i32(self) -> Option<i32> {
+        self.integer().to_i32()
+    }
+
+    /// Integer part truncated towards zero
+    ///
+    /// # Panics
+    ///
+    /// Panics if integer part doesn't fit in `i32`
+    #[must_use]
+    pub fn to_i32(self) -> i32 {
+        self.try_to_i32().expect("Integer part out of range of i32")
     }
 }
 
-impl<T: convert::TryFrom<isize>, const D: u8> FromStr for FixedPoint<T, D> {
-    type Err = ();
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + ops::Mul<Output = T> + ops::Div<Output = T>,
+{
+    /// Convert to decimal length `E`, digits beyond `E` are truncated
+    #[must_use]
+    pub fn rescale<const E: u8>(self) -> FixedPoint<T, E> {
+        let () = FixedPoint::<T, E>::VALID_DECIMAL_LENGTH;
+        if E >= D {
+            FixedPoint(self.0 * T::POWERS_OF_TEN[(E - D) as usize])
+        } else {
+            FixedPoint(self.0 / T::POWERS_OF_TEN[(D - E) as usize])
+        }
+    }
 
-    fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let negative = string.chars().next().map(|c| c == '-').unwrap_or(false);
-        let mut splitted = string.split('.');
-        let mut integer = splitted
-            .next()
-            .ok_or(())?
-            .parse::<isize>()
-            .map_err(|_| ())?;
-        integer *= (10 as isize).pow(D as u32);
-        let field = match splitted.next() {
-            Some(s) => s,
-            None => return T::try_from(integer).map(|v| Self(v)).map_err(|_| ()),
-        };
-        let decimal_length = core::cmp::min(field.len(), 255) as u8;
-        let mut decimal = field.parse::<isize>().map_err(|_| ())?;
-        if integer < 0 || negative {
-            decimal = -decimal
-        }
-        if D >= decimal_length {
-            decimal *= (10 as isize).pow((D - decimal_length) as u32);
+    /// Same as `rescale` but fails to compile if `E < D`, so precision is never lost
+    #[must_use]
+    pub fn to_higher<const E: u8>(self) -> FixedPoint<T, E> {
+        const { assert!(E >= D, "Target decimal length E less than D") };
+        self.rescale()
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + PartialOrd + CheckedMul + ops::Div<Output = T>,
+{
+    /// Same as `rescale`, returns `None` on overflow
+    #[must_use]
+    pub fn checked_rescale<const E: u8>(self) -> Option<FixedPoint<T, E>> {
+        let () = FixedPoint::<T, E>::VALID_DECIMAL_LENGTH;
+        if E >= D {
+            let exp = T::POWERS_OF_TEN[(E - D) as usize];
+            self.0.checked_mul(&exp).map(FixedPoint)
         } else {
-            decimal /= (10 as isize).pow((decimal_length - D) as u32);
+            Some(FixedPoint(self.0 / T::POWERS_OF_TEN[(D - E) as usize]))
+        }
+    }
+
+    /// Same as `rescale`, saturates to `T::min_value()` or `T::max_value()` on overflow
+    #[must_use]
+    pub fn saturating_rescale<const E: u8>(self) -> FixedPoint<T, E> {
+        match self.checked_rescale() {
+            Some(value) => value,
+            None if self.0 < T::zero() => FixedPoint(T::min_value()),
+            None => FixedPoint(T::max_value()),
         }
-        T::try_from(integer + decimal)
-            .map(|v| Self(v))
-            .map_err(|_| ())
     }
 }
 
-impl<T, const D: u8> Display for FixedPoint<T, D>
+impl<T: PrimInt, const D: u8> FixedPoint<T, D> {
+    /// Convert to backing type `U` of same decimal length, raw value is clamped
+    /// to range of `U`
+    #[must_use]
+    pub fn saturating_into<U: PrimInt + Number>(self) -> FixedPoint<U, D> {
+        let () = FixedPoint::<U, D>::VALID_DECIMAL_LENGTH;
+        match num_traits::cast(self.0) {
+            Some(raw) => FixedPoint(raw),
+            None if self.0 < T::zero() => FixedPoint(<U as Number>::min_value()),
+            None => FixedPoint(<U as Number>::max_value()),
+        }
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
 where
-    T: Copy
-        + Display
-        + Into<i32>
-        + PartialEq
-        + Number
-        + PartialOrd
-        + Pow<u32, Output = T>
-        + ops::Div<Output = T>
-        + ops::Rem<Output = T>,
+    T: Copy + Number + WrappingMul + ops::Div<Output = T>,
 {
-    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        let mut decimal = self.decimal().into().abs();
-        if D == 0 || decimal == 0 {
-            return write!(f, "{}.0", self.integer());
+    /// Same as `rescale`, wraps around on overflow
+    #[must_use]
+    pub fn wrapping_rescale<const E: u8>(self) -> FixedPoint<T, E> {
+        let () = FixedPoint::<T, E>::VALID_DECIMAL_LENGTH;
+        if E >= D {
+            FixedPoint(self.0.wrapping_mul(&T::POWERS_OF_TEN[(E - D) as usize]))
+        } else {
+            FixedPoint(self.0 / T::POWERS_OF_TEN[(D - E) as usize])
         }
-        let mut length = D;
-        while decimal % 10 == 0 {
-            decimal = decimal / 10;
-            length -= 1;
+    }
+}
+
+impl<T: num_traits::Euclid, const D: u8> FixedPoint<T, D> {
+    /// Least non-negative remainder of same decimal length,
+    /// e.g. `-10.0` by `360.0` is `350.0`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or on overflow of `T::MIN` by `-1`
+    #[must_use]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self(self.0.rem_euclid(&rhs.0))
+    }
+
+    /// Quotient in whole units rounded such that `self == rhs * q + self.rem_euclid(rhs)`,
+    /// e.g. `-10.0` by `360.0` is `-1`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, or on overflow of `T::MIN` by `-1`
+    #[must_use]
+    pub fn div_euclid(self, rhs: Self) -> T {
+        self.0.div_euclid(&rhs.0)
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedMul + CheckedRem,
+{
+    /// Remainder of same decimal length, returns `None` if `rhs` is zero or on overflow
+    #[must_use]
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(&rhs.0).map(Self)
+    }
+
+    /// Remainder by whole units of `T`, returns `None` if `rhs` is zero or on overflow
+    #[must_use]
+    pub fn checked_rem_int(self, rhs: T) -> Option<Self> {
+        match rhs.checked_mul(&Self::EXP_T) {
+            Some(rhs) => self.0.checked_rem(&rhs).map(Self),
+            // |rhs| exceeds any representable value thus exceeds |self|
+            None => Some(self),
+        }
+    }
+}
+
+impl<T: Copy + Number + WrappingAdd + SaturatingAdd, const D: u8> FixedPoint<T, D> {
+    /// Running totals of `iter`, wrapping around on overflow
+    pub fn cumulative_sum<I>(iter: I) -> impl Iterator<Item = Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter().scan(T::zero(), |sum, v| {
+            *sum = sum.wrapping_add(&v.0);
+            Some(Self(*sum))
+        })
+    }
+
+    /// Running totals of `iter`, saturating at `T::min_value()` or `T::max_value()`
+    pub fn saturating_cumulative_sum<I>(iter: I) -> impl Iterator<Item = Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        iter.into_iter().scan(T::zero(), |sum, v| {
+            *sum = sum.saturating_add(&v.0);
+            Some(Self(*sum))
+        })
+    }
+}
+
+impl<T: PrimInt, const D: u8> FixedPoint<T, D> {
+    /// Average of `self` and `other` rounded towards negative infinity, never overflows
+    #[must_use]
+    pub fn midpoint(self, other: Self) -> Self {
+        Self((self.0 & other.0) + ((self.0 ^ other.0) >> 1))
+    }
+}
+
+impl<T: Copy + Number + PartialOrd + CheckedNeg, const D: u8> FixedPoint<T, D> {
+    /// Absolute value, returns `None` if it isn't representable, i.e. `T::MIN`
+    #[must_use]
+    pub fn checked_abs(self) -> Option<Self> {
+        if self.0 < T::zero() {
+            self.0.checked_neg().map(Self)
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Returns `None` if negation isn't representable, e.g. `T::MIN` or positive unsigned
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + PartialOrd + CheckedNeg,
+{
+    /// Negation saturating at `T::max_value()` for `T::MIN`, or `T::min_value()` for
+    /// positive unsigned
+    #[must_use]
+    pub fn saturating_neg(self) -> Self {
+        match self.checked_neg() {
+            Some(value) => value,
+            None if self.0 < T::zero() => Self(T::max_value()),
+            None => Self(T::min_value()),
+        }
+    }
+}
+
+impl<T: Copy + Number + PartialOrd, const D: u8> FixedPoint<T, D> {
+    /// Clamp to `[0.0, 1.0]`
+    #[must_use]
+    pub fn clamp01(self) -> Self {
+        if self.0 < T::zero() {
+            Self(T::zero())
+        } else if self.0 > Self::EXP_T {
+            Self(Self::EXP_T)
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Copy + Number + PartialOrd + ops::Neg<Output = T>, const D: u8> FixedPoint<T, D> {
+    /// Limit absolute value to `max` preserving sign,
+    /// e.g. `-3.0` clamped by magnitude `2.0` is `-2.0`
+    ///
+    /// `max` is expected to be non-negative, which is checked in debug mode
+    #[must_use]
+    pub fn clamp_magnitude(self, max: Self) -> Self {
+        debug_assert!(max.0 >= T::zero(), "Negative magnitude");
+        if self.0 > max.0 {
+            max
+        } else if self.0 < -max.0 {
+            Self(-max.0)
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Number + SaturatingAdd, const D: u8> FixedPoint<T, D> {
+    /// Sum saturating at `T::min_value()` or `T::max_value()` instead of overflowing
+    #[must_use]
+    pub fn saturating_sum<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        let sum = iter
+            .into_iter()
+            .fold(T::zero(), |sum, v| sum.saturating_add(&v.0));
+        Self(sum)
+    }
+}
+
+/// How digits beyond target decimal length are discarded
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Truncate towards zero
+    Truncate,
+    /// Round to nearest, ties away from zero
+    HalfAwayFromZero,
+    /// Round to nearest, ties to even
+    HalfEven,
+}
+
+impl<T: Number + PrimInt, const D: u8> FixedPoint<T, D> {
+    fn round_with(self, digits: u8, ties_even: bool) -> Option<Self> {
+        if digits >= D {
+            return Some(self);
+        }
+        let exp = T::POWERS_OF_TEN[(D - digits) as usize];
+        self.rounded_quotient(exp, ties_even)
+            .checked_mul(&exp)
+            .map(Self)
+    }
+
+    /// `self.0 / exp` rounded to nearest
+    fn rounded_quotient(self, exp: T, ties_even: bool) -> T {
+        let (zero, one) = (<T as Number>::zero(), T::one());
+        let (mut quotient, mut remainder) = (self.0 / exp, self.0 % exp);
+        let negative = remainder < zero;
+        if negative {
+            remainder = zero - remainder;
+        }
+        let half = exp / (one + one);
+        let odd = quotient % (one + one) != zero;
+        if remainder > half || (remainder == half && (!ties_even || odd)) {
+            quotient = if negative {
+                quotient - one
+            } else {
+                quotient + one
+            };
+        }
+        quotient
+    }
+
+    /// Convert to decimal length `E`, digits beyond `E` are discarded according to `mode`,
+    /// returns `None` on overflow when `E > D`
+    #[must_use]
+    pub fn checked_rescale_round<const E: u8>(
+        self,
+        mode: RoundingMode,
+    ) -> Option<FixedPoint<T, E>> {
+        let () = FixedPoint::<T, E>::VALID_DECIMAL_LENGTH;
+        if E >= D {
+            return self
+                .0
+                .checked_mul(&T::POWERS_OF_TEN[(E - D) as usize])
+                .map(FixedPoint);
+        }
+        let exp = T::POWERS_OF_TEN[(D - E) as usize];
+        let quotient = match mode {
+            RoundingMode::Truncate => self.0 / exp,
+            RoundingMode::HalfAwayFromZero => self.rounded_quotient(exp, false),
+            RoundingMode::HalfEven => self.rounded_quotient(exp, true),
+        };
+        Some(FixedPoint(quotient))
+    }
+
+    /// Round to `digits` fractional digits, ties away from zero
+    ///
+    /// # Panics
+    ///
+    /// Panics if rounding away from zero overflows, e.g. `T::MAX` with nonzero
+    /// digits beyond `digits`
+    #[must_use]
+    pub fn round_to(self, digits: u8) -> Self {
+        self.round_with(digits, false)
+            .expect("fixed-point overflow")
+    }
+
+    /// Same as `round_to`, returns `None` on overflow
+    #[must_use]
+    pub fn checked_round_to(self, digits: u8) -> Option<Self> {
+        self.round_with(digits, false)
+    }
+
+    /// Round to `digits` fractional digits, ties to even, a.k.a. banker's rounding
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow, same as `round_to`
+    #[must_use]
+    pub fn round_ties_even_to(self, digits: u8) -> Self {
+        self.round_with(digits, true).expect("fixed-point overflow")
+    }
+
+    /// Round every element in place to `digits` fractional digits, ties to even
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow, same as `round_ties_even_to`
+    pub fn round_slice_to(slice: &mut [Self], digits: u8) {
+        for value in slice.iter_mut() {
+            *value = value.round_ties_even_to(digits);
         }
-        let integer = self.integer();
-        if integer == T::zero() && self.0 < T::zero() {
-            write!(f, "-0.{:0length$}", decimal, length = length as usize)
+    }
+
+    /// Raw integer value at `scale` fractional digits rounding half away from zero,
+    /// e.g. `1.2345` at scale `2` is `123`, returns `None` on overflow
+    #[must_use]
+    pub fn to_scaled_int(self, scale: u8) -> Option<T> {
+        if scale >= D {
+            let exp = T::POWERS_OF_TEN.get((scale - D) as usize)?;
+            return self.0.checked_mul(exp);
+        }
+        Some(self.rounded_quotient(T::POWERS_OF_TEN[(D - scale) as usize], false))
+    }
+
+    /// Same as `to_scaled_int` but saturates to `T::min_value()` or `T::max_value()`
+    /// on overflow
+    #[must_use]
+    pub fn saturating_to_scaled_int(self, scale: u8) -> T {
+        let zero = <T as Number>::zero();
+        match self.to_scaled_int(scale) {
+            Some(raw) => raw,
+            None if self.0 == zero => zero,
+            None if self.0 < zero => <T as Number>::min_value(),
+            None => <T as Number>::max_value(),
+        }
+    }
+
+    /// Round to nearest multiple of `step`, ties away from zero,
+    /// e.g. `0.12` quantized by `0.05` is `0.10`.
+    ///
+    /// Returns `self` unchanged if `step` isn't positive, and nearest multiple towards
+    /// zero if the other one is out of range
+    #[must_use]
+    pub fn quantize(self, step: Self) -> Self {
+        let (zero, step) = (<T as Number>::zero(), step.0);
+        if step <= zero {
+            return self;
+        }
+        let remainder = self.0 % step;
+        let truncated = self.0 - remainder;
+        let magnitude = if remainder < zero {
+            zero - remainder
         } else {
-            write!(
-                f,
-                "{}.{:0length$}",
-                integer,
-                decimal,
-                length = length as usize
-            )
+            remainder
+        };
+        if magnitude < step - magnitude {
+            return Self(truncated);
         }
+        let rounded = if remainder < zero {
+            truncated.checked_sub(&step)
+        } else {
+            truncated.checked_add(&step)
+        };
+        Self(rounded.unwrap_or(truncated))
     }
 }
 
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy
+        + Number
+        + PartialOrd
+        + ops::Sub<Output = T>
+        + ops::Div<Output = T>
+        + ops::Rem<Output = T>,
+{
+    /// Numerator and denominator, i.e. `(raw, 10^D)`
+    #[must_use]
+    pub fn to_rational(self) -> (T, T) {
+        (self.0, Self::EXP_T)
+    }
+
+    /// Same as `to_rational` but divided by greatest common divisor, e.g. `0.50` is `(1, 2)`
+    #[must_use]
+    pub fn to_rational_reduced(self) -> (T, T) {
+        let (numerator, denominator) = self.to_rational();
+        let (mut a, mut b) = (denominator, numerator);
+        while b != T::zero() {
+            (a, b) = (b, a % b);
+        }
+        if a < T::zero() {
+            a = T::zero() - a;
+        }
+        (numerator / a, denominator / a)
+    }
+}
+
+impl<T: CheckedMul, const D: u8> FixedPoint<T, D> {
+    /// Multiply by `n`, returns `None` on overflow
+    #[must_use]
+    pub fn checked_mul_int(self, n: T) -> Option<Self> {
+        self.0.checked_mul(&n).map(Self)
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + PartialOrd + CheckedAdd + CheckedSub + CheckedMul,
+{
+    /// Add whole units of `T`, returns `None` if scaled `n` or sum overflows
+    #[must_use]
+    pub fn checked_add_int(self, n: T) -> Option<Self> {
+        let n = n.checked_mul(&Self::EXP_T)?;
+        self.0.checked_add(&n).map(Self)
+    }
+
+    /// Subtract whole units of `T`, returns `None` if scaled `n` or difference overflows
+    #[must_use]
+    pub fn checked_sub_int(self, n: T) -> Option<Self> {
+        let n = n.checked_mul(&Self::EXP_T)?;
+        self.0.checked_sub(&n).map(Self)
+    }
+
+    /// Add whole units of `T`, saturating at `T::min_value()` or `T::max_value()`
+    #[must_use]
+    pub fn saturating_add_int(self, n: T) -> Self {
+        match self.checked_add_int(n) {
+            Some(value) => value,
+            None if n < T::zero() => Self(T::min_value()),
+            None => Self(T::max_value()),
+        }
+    }
+
+    /// Subtract whole units of `T`, saturating at `T::min_value()` or `T::max_value()`
+    #[must_use]
+    pub fn saturating_sub_int(self, n: T) -> Self {
+        match self.checked_sub_int(n) {
+            Some(value) => value,
+            None if n < T::zero() => Self(T::max_value()),
+            None => Self(T::min_value()),
+        }
+    }
+}
+
+impl<T: Copy + Number + PartialEq + CheckedDiv, const D: u8> FixedPoint<T, D> {
+    /// Divide by `n`, returns `None` if `n` is zero or on overflow, i.e. `T::MIN / -1`
+    #[must_use]
+    pub fn checked_div_int(self, n: T) -> Option<Self> {
+        self.0.checked_div(&n).map(Self)
+    }
+
+    /// Divide by `n`, saturating at `T::max_value()` for `T::MIN / -1`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero
+    #[must_use]
+    pub fn saturating_div_int(self, n: T) -> Self {
+        match self.checked_div_int(n) {
+            Some(value) => value,
+            None if n == T::zero() => panic!("Division by zero"),
+            None => Self(T::max_value()),
+        }
+    }
+}
+
+impl<T: Copy + Widen, const D: u8> FixedPoint<T, D> {
+    /// Multiply by `num / den` with a widened intermediate, truncating towards zero,
+    /// e.g. `10.0` inches `mul_ratio(254, 100)` is `25.4` cm
+    ///
+    /// # Panics
+    ///
+    /// Panics if `den` is zero or result out of range of `T`
+    #[must_use]
+    pub fn mul_ratio(self, num: T, den: T) -> Self {
+        let wide = self.0.into() * num.into() / den.into();
+        Self(T::try_from(wide).ok().expect("fixed-point overflow"))
+    }
+
+    /// Same as `mul_ratio` but rounds half away from zero,
+    /// returns `None` if `den` is zero or result out of range of `T`
+    #[must_use]
+    pub fn checked_mul_ratio(self, num: T, den: T) -> Option<Self> {
+        let (product, den) = (self.0.into() * num.into(), den.into());
+        let (zero, one): (T::Wide, T::Wide) = (num_traits::Zero::zero(), num_traits::One::one());
+        if den == zero {
+            return None;
+        }
+        let abs = |value: T::Wide| if value < zero { zero - value } else { value };
+        let (mut quotient, remainder) = (product / den, abs(product % den));
+        if remainder >= abs(den) - remainder {
+            let negative = (product < zero) != (den < zero);
+            quotient = if negative {
+                quotient - one
+            } else {
+                quotient + one
+            };
+        }
+        T::try_from(quotient).ok().map(Self)
+    }
+}
+
+impl<T: Copy + Number + Widen, const D: u8> FixedPoint<T, D> {
+    /// Sum of element-wise products accumulated in wide backing type, truncating towards
+    /// zero, returns `None` if lengths mismatch or sum out of range of `T::Wide`
+    #[must_use]
+    pub fn dot(a: &[Self], b: &[Self]) -> Option<FixedPoint<T::Wide, D>> {
+        if a.len() != b.len() {
+            return None;
+        }
+        let mut sum: T::Wide = num_traits::Zero::zero();
+        for (x, y) in a.iter().zip(b) {
+            sum = sum.checked_add(&(x.0.into() * y.0.into()))?;
+        }
+        Some(FixedPoint(sum / Self::EXP_T.into()))
+    }
+}
+
+impl<T: SignedWiden, const D: u8> FixedPoint<T, D> {
+    /// Difference in signed wide backing type, never overflows,
+    /// e.g. `fixed!(1u32, 2).sub_signed(fixed!(2u32, 2))` is `-1.0` in `i64`
+    #[must_use]
+    pub fn sub_signed(self, rhs: Self) -> FixedPoint<T::SignedWide, D> {
+        FixedPoint(self.0.into() - rhs.0.into())
+    }
+}
+
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    T: Copy + Number + SignedWiden + convert::TryFrom<T::SignedWide>,
+{
+    /// `(self - baseline) / baseline * 100` with a signed widened intermediate, truncating
+    /// towards zero, e.g. `1.5` against `1.2` is `25.0`, returns `None` if `baseline` is
+    /// zero or result out of range of `T`
+    #[must_use]
+    pub fn percent_change(self, baseline: Self) -> Option<Self> {
+        let (value, baseline) = (self.0.into(), baseline.0.into());
+        let zero: T::SignedWide = num_traits::Zero::zero();
+        if baseline == zero {
+            return None;
+        }
+        let hundred: T::SignedWide = num_traits::cast(100)?;
+        let scale = hundred.checked_mul(&Self::EXP_T.into())?;
+        let change = (value - baseline).checked_mul(&scale)? / baseline;
+        T::try_from(change).ok().map(Self)
+    }
+
+    /// Linearly map from range `in_lo..in_hi` to `out_lo..out_hi` with a 128-bit
+    /// intermediate, truncating towards zero, e.g. `0.5` from `0..1` to `0..100` is `50.0`.
+    ///
+    /// Values outside of input range are extrapolated, and ranges may be descending
+    ///
+    /// # Panics
+    ///
+    /// Panics if `in_lo == in_hi` or result out of range of `T`
+    #[must_use]
+    pub fn map_range(self, in_lo: Self, in_hi: Self, out_lo: Self, out_hi: Self) -> Self {
+        let [value, in_lo, in_hi, out_lo, out_hi] =
+            [self, in_lo, in_hi, out_lo, out_hi].map(|v| v.0.into());
+        assert!(in_lo != in_hi, "Empty input range");
+        let wide = |v: T::SignedWide| num_traits::cast::<_, i128>(v);
+        let raw = wide(value - in_lo)
+            .zip(wide(out_hi - out_lo))
+            .zip(wide(in_hi - in_lo))
+            .and_then(|((a, b), c)| mul_div_i128(a, b, c))
+            .zip(wide(out_lo))
+            .and_then(|(delta, out_lo)| num_traits::cast(delta.checked_add(out_lo)?))
+            .and_then(|wide: T::SignedWide| T::try_from(wide).ok());
+        Self(raw.expect("fixed-point overflow"))
+    }
+}
+
+/// Zero regardless of what `T::default()` is
+impl<T: Number, const D: u8> Default for FixedPoint<T, D> {
+    fn default() -> Self {
+        Self(T::zero())
+    }
+}
+
+/// `a * b / c` truncating towards zero, product of magnitudes is computed in `u128`
+/// so it never overflows for factors up to 64 bits
+fn mul_div_i128(a: i128, b: i128, c: i128) -> Option<i128> {
+    let product = a.unsigned_abs().checked_mul(b.unsigned_abs())?;
+    let quotient = i128::try_from(product.checked_div(c.unsigned_abs())?).ok()?;
+    let negative = ((a < 0) != (b < 0)) != (c < 0);
+    Some(if negative { -quotient } else { quotient })
+}
+
+/// Unwrap result of checked arithmetic with `strict-arithmetic` feature
+fn strict<T>(checked: Option<T>) -> T {
+    checked.expect("fixed-point overflow")
+}
+
+impl<T, const D: u8> AsRef<T> for FixedPoint<T, D> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const D: u8> AsMut<T> for FixedPoint<T, D> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Product keeps decimal length of left-hand side, e.g. `D = 2` times `E = 4` yields `D = 2`,
+/// digits beyond are truncated.
+///
+/// Raw values are multiplied in a widened intermediate, so only the result needs to fit
+/// in `T`, except for `u128` and `i128` where the raw product itself must fit
+impl<T, const D: u8, const E: u8> ops::Mul<FixedPoint<T, E>> for FixedPoint<T, D>
+where
+    T: Copy + Number + ops::Mul<Output = T> + ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: FixedPoint<T, E>) -> Self {
+        let exp = FixedPoint::<T, E>::EXP_T;
+        match self.0.checked_mul_div(rhs.0, exp) {
+            Some(raw) => Self(raw),
+            None if cfg!(feature = "strict-arithmetic") => strict(None),
+            // Raw product overflows as well, so this behaves like backing type
+            None => Self(self.0 * rhs.0 / exp),
+        }
+    }
+}
+
+impl<T: CheckedMul, const D: u8> ops::Mul<T> for FixedPoint<T, D> {
+    type Output = Self;
+
+    fn mul(self, n: T) -> Self {
+        if cfg!(feature = "strict-arithmetic") {
+            return Self(strict(self.0.checked_mul(&n)));
+        }
+        Self(self.0 * n)
+    }
+}
+
+impl<T: ops::Div<Output = T>, const D: u8> ops::Div<T> for FixedPoint<T, D> {
+    type Output = Self;
+
+    fn div(self, div: T) -> Self {
+        Self(self.0 / div)
+    }
+}
+
+/// Negating `T::MIN` overflows like backing type, use `checked_neg` or `saturating_neg`
+/// to handle it explicitly
+impl<T: CheckedNeg + ops::Neg<Output = T>, const D: u8> ops::Neg for FixedPoint<T, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if cfg!(feature = "strict-arithmetic") {
+            return Self(strict(self.0.checked_neg()));
+        }
+        Self(-self.0)
+    }
+}
+
+impl<T: CheckedAdd, const D: u8> ops::Add for FixedPoint<T, D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        if cfg!(feature = "strict-arithmetic") {
+            return Self(strict(self.0.checked_add(&rhs.0)));
+        }
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T: CheckedSub, const D: u8> ops::Sub for FixedPoint<T, D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        if cfg!(feature = "strict-arithmetic") {
+            return Self(strict(self.0.checked_sub(&rhs.0)));
+        }
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T: Copy + CheckedAdd, const D: u8> ops::AddAssign for FixedPoint<T, D> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Copy + CheckedSub, const D: u8> ops::SubAssign for FixedPoint<T, D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<T: Number + CheckedAdd, const D: u8> iter::Sum for FixedPoint<T, D> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(T::zero()), |sum, v| sum + v)
+    }
+}
+
+impl<'a, T: Copy + Number + CheckedAdd, const D: u8> iter::Sum<&'a Self> for FixedPoint<T, D> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Add whole units of `T`, e.g. `fixed!(1.5i16, 2) + 1` is `2.5`
+impl<T, const D: u8> ops::Add<T> for FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedAdd + CheckedMul,
+{
+    type Output = Self;
+
+    fn add(self, integer: T) -> Self {
+        if cfg!(feature = "strict-arithmetic") {
+            let integer = integer.checked_mul(&Self::EXP_T);
+            return Self(strict(integer.and_then(|v| self.0.checked_add(&v))));
+        }
+        Self(self.0 + integer * Self::EXP_T)
+    }
+}
+
+/// Subtract whole units of `T`
+impl<T, const D: u8> ops::Sub<T> for FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedSub + CheckedMul,
+{
+    type Output = Self;
+
+    fn sub(self, integer: T) -> Self {
+        if cfg!(feature = "strict-arithmetic") {
+            let integer = integer.checked_mul(&Self::EXP_T);
+            return Self(strict(integer.and_then(|v| self.0.checked_sub(&v))));
+        }
+        Self(self.0 - integer * Self::EXP_T)
+    }
+}
+
+impl<T, const D: u8> ops::AddAssign<T> for FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedAdd + CheckedMul,
+{
+    fn add_assign(&mut self, integer: T) {
+        *self = *self + integer
+    }
+}
+
+impl<T, const D: u8> ops::SubAssign<T> for FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedSub + CheckedMul,
+{
+    fn sub_assign(&mut self, integer: T) {
+        *self = *self - integer
+    }
+}
+
+/// `FixedPoint` tagged with unit of measure `U`, e.g. `Quantity<i32, 2, Meter>`,
+/// arithmetic only compiles between quantities of same unit.
+///
+/// Unit is carried by a separate type since adding field to `FixedPoint`
+/// would break constructing it as `FixedPoint(raw)`
+#[repr(transparent)]
+pub struct Quantity<T, const D: u8, U>(pub FixedPoint<T, D>, PhantomData<U>);
+
+impl<T, const D: u8, U> Quantity<T, D, U> {
+    #[must_use]
+    pub const fn new(value: FixedPoint<T, D>) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Drop the unit
+    #[must_use]
+    pub fn value(self) -> FixedPoint<T, D> {
+        self.0
+    }
+}
+
+impl<T: Copy, const D: u8, U> Copy for Quantity<T, D, U> {}
+
+impl<T: Clone, const D: u8, U> Clone for Quantity<T, D, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T: core::fmt::Debug, const D: u8, U> core::fmt::Debug for Quantity<T, D, U> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "Quantity<{}>({:?})", core::any::type_name::<U>(), self.0)
+    }
+}
+
+impl<T: PartialEq, const D: u8, U> PartialEq for Quantity<T, D, U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, const D: u8, U> Eq for Quantity<T, D, U> {}
+
+impl<T: PartialOrd, const D: u8, U> PartialOrd for Quantity<T, D, U> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T, const D: u8, U> Display for Quantity<T, D, U>
+where
+    FixedPoint<T, D>: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: CheckedAdd, const D: u8, U> ops::Add for Quantity<T, D, U> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl<T: CheckedSub, const D: u8, U> ops::Sub for Quantity<T, D, U> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl<T: Copy + CheckedAdd, const D: u8, U> ops::AddAssign for Quantity<T, D, U> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<T: Copy + CheckedSub, const D: u8, U> ops::SubAssign for Quantity<T, D, U> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+/// Inclusive range of fixed-point values, always non-empty
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedRange<T, const D: u8> {
+    min: FixedPoint<T, D>,
+    max: FixedPoint<T, D>,
+}
+
+impl<T: Copy + PartialOrd, const D: u8> FixedRange<T, D> {
+    /// Returns `None` if `min > max`
+    #[must_use]
+    pub fn new(min: FixedPoint<T, D>, max: FixedPoint<T, D>) -> Option<Self> {
+        if min > max {
+            return None;
+        }
+        Some(Self { min, max })
+    }
+
+    #[must_use]
+    pub fn min(&self) -> FixedPoint<T, D> {
+        self.min
+    }
+
+    #[must_use]
+    pub fn max(&self) -> FixedPoint<T, D> {
+        self.max
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: FixedPoint<T, D>) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Restrict `value` to this range
+    #[must_use]
+    pub fn clamp(&self, value: FixedPoint<T, D>) -> FixedPoint<T, D> {
+        if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        }
+    }
+}
+
+/// Fixed-point number with decimal length erased into runtime value,
+/// compared by real value so mixed decimal lengths can be sorted together
+#[derive(Copy, Clone, Debug)]
+pub struct AnyFixed<T> {
+    raw: T,
+    decimal_length: u8,
+}
+
+impl<T: Copy> AnyFixed<T> {
+    /// Raw value in units of `10^-decimal_length`
+    #[must_use]
+    pub fn raw(&self) -> T {
+        self.raw
+    }
+
+    #[must_use]
+    pub fn decimal_length(&self) -> u8 {
+        self.decimal_length
+    }
+}
+
+impl<T: Number, const D: u8> From<FixedPoint<T, D>> for AnyFixed<T> {
+    fn from(value: FixedPoint<T, D>) -> Self {
+        let () = FixedPoint::<T, D>::VALID_DECIMAL_LENGTH;
+        Self {
+            raw: value.0,
+            decimal_length: D,
+        }
+    }
+}
+
+/// Rescale to `D`, fails with `ParseError::PrecisionLoss` if decimal length exceeds `D`
+/// or `ParseError::Overflow` if out of range
+impl<T, const D: u8> convert::TryFrom<AnyFixed<T>> for FixedPoint<T, D>
+where
+    T: Copy + Number + CheckedMul + ops::Div<Output = T>,
+{
+    type Error = ParseError;
+
+    fn try_from(value: AnyFixed<T>) -> Result<Self, ParseError> {
+        let Some(digits) = D.checked_sub(value.decimal_length) else {
+            return Err(ParseError::PrecisionLoss);
+        };
+        let exp = T::POWERS_OF_TEN.get(digits as usize);
+        let raw = exp.and_then(|exp| value.raw.checked_mul(exp));
+        raw.map(Self).ok_or(ParseError::Overflow)
+    }
+}
+
+impl<T> Ord for AnyFixed<T>
+where
+    T: Copy + Number + Ord + ops::Mul<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T>,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let parts = |value: &Self| {
+            let exp = T::POWERS_OF_TEN[value.decimal_length as usize];
+            (value.raw / exp, value.raw % exp)
+        };
+        let ((a, a_fraction), (b, b_fraction)) = (parts(self), parts(other));
+        // |fraction| < 10^length, so scaling up to the longer length never overflows
+        let length = core::cmp::max(self.decimal_length, other.decimal_length);
+        let scale = |fraction, decimal_length: u8| {
+            fraction * T::POWERS_OF_TEN[(length - decimal_length) as usize]
+        };
+        let a_fraction = scale(a_fraction, self.decimal_length);
+        let b_fraction = scale(b_fraction, other.decimal_length);
+        a.cmp(&b).then(a_fraction.cmp(&b_fraction))
+    }
+}
+
+impl<T> PartialOrd for AnyFixed<T>
+where
+    T: Copy + Number + Ord + ops::Mul<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Equal by real value, e.g. `1.0` equals `1.00`
+impl<T> PartialEq for AnyFixed<T>
+where
+    T: Copy + Number + Ord + ops::Mul<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl<T> Eq for AnyFixed<T> where
+    T: Copy + Number + Ord + ops::Mul<Output = T> + ops::Div<Output = T> + ops::Rem<Output = T>
+{
+}
+
+/// Same as `Display` of `FixedPoint`, flags and precision included
+impl<T> Display for AnyFixed<T>
+where
+    T: Copy
+        + Display
+        + Number
+        + PartialOrd
+        + ops::Add<Output = T>
+        + ops::Sub<Output = T>
+        + ops::Div<Output = T>
+        + ops::Rem<Output = T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_scaled(f, self.raw, self.decimal_length)
+    }
+}
+
+/// Average of last `N` samples, accumulated in widened type of `T`.
+///
+/// Widened type holds sum of at most `2^bits` samples of `T`, so `N` beyond that fails to
+/// compile, e.g. `N <= 256` for `u8`
+#[derive(Copy, Clone, Debug)]
+pub struct MovingAverage<T: Widen, const D: u8, const N: usize> {
+    samples: [FixedPoint<T, D>; N],
+    length: usize,
+    index: usize,
+    sum: T::Wide,
+}
+
+impl<T: Copy + Number + Widen, const D: u8, const N: usize> MovingAverage<T, D, N> {
+    #[must_use]
+    pub fn new() -> Self {
+        const { assert!(N > 0, "Window size N must be positive") };
+        const {
+            let limit = 1_u128 << (8 * core::mem::size_of::<T>());
+            assert!(N as u128 <= limit, "Window size N overflows widened sum")
+        };
+        Self {
+            samples: [FixedPoint(T::zero()); N],
+            length: 0,
+            index: 0,
+            sum: num_traits::Zero::zero(),
+        }
+    }
+
+    /// Push a sample and return average of at most last `N` samples, truncating towards zero
+    pub fn push(&mut self, value: FixedPoint<T, D>) -> FixedPoint<T, D> {
+        if self.length == N {
+            self.sum = self.sum - self.samples[self.index].0.into();
+        } else {
+            self.length += 1;
+        }
+        self.samples[self.index] = value;
+        self.sum = self.sum + value.0.into();
+        self.index = (self.index + 1) % N;
+        let length = num_traits::cast(self.length).expect("Window size out of range");
+        let average = T::try_from(self.sum / length).ok();
+        FixedPoint(average.expect("Average always in range of T"))
+    }
+}
+
+impl<T: Copy + Number + Widen, const D: u8, const N: usize> Default for MovingAverage<T, D, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + ToPrimitive + num_traits::NumCast, const D: u8> FixedPoint<T, D> {
+    /// Nearest value rounding half away from zero, returns `None` if `value` is NaN
+    /// or out of range
+    #[must_use]
+    pub fn from_f64(value: f64) -> Option<Self> {
+        let exp = FloatCore::powi(10f64, D as i32);
+        num_traits::cast(FloatCore::round(value * exp)).map(Self)
+    }
+
+    /// Nearest `f64`, converts back losslessly with `from_f64` if |raw value| <= `2^50`
+    #[must_use]
+    pub fn to_f64(self) -> f64 {
+        let exp = FloatCore::powi(10f64, D as i32);
+        self.0.to_f64().unwrap_or(f64::NAN) / exp
+    }
+
+    /// Apply `f` on `f64` value and convert back rounding to `D` digits,
+    /// `None` if result is NaN or out of range
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn map_f64(self, f: impl FnOnce(f64) -> f64) -> Option<Self> {
+        Self::from_f64(f(self.to_f64()))
+    }
+}
+
+impl<T: Copy + 'static, const D: u8> FixedPoint<T, D>
+where
+    i128: num_traits::AsPrimitive<T>,
+{
+    /// Scale `value` rounding to nearest, then wrap around into range of `T`
+    /// like `as` casting, e.g. `2.56` is `0.00` for `FixedPoint<u8, 2>`.
+    ///
+    /// This is rarely what you want, see `from_f64` for checked conversion.
+    /// NaN converts to zero and scaled values beyond range of `i128` saturate before wrapping
+    #[must_use]
+    pub fn wrapping_from_f32(value: f32) -> Self {
+        let exp = FloatCore::powi(10f64, D as i32);
+        let scaled = FloatCore::round(value as f64 * exp) as i128;
+        Self(num_traits::AsPrimitive::as_(scaled))
+    }
+}
+
+/// Float functions are computed through `f64` round trip, so results are subject to `f64`
+/// precision of about 15 significant digits and may be off by one unit of last digit
+#[cfg(feature = "std")]
+impl<T: Copy + ToPrimitive + num_traits::NumCast, const D: u8> FixedPoint<T, D> {
+    /// Raise to fractional power `exp`, returns `None` if result is NaN or out of range
+    #[must_use]
+    pub fn powf(self, exp: f64) -> Option<Self> {
+        self.map_f64(|value| value.powf(exp))
+    }
+
+    /// Natural logarithm, returns `None` if not positive
+    #[must_use]
+    pub fn ln(self) -> Option<Self> {
+        self.map_f64(f64::ln)
+    }
+
+    /// Base 10 logarithm, returns `None` if not positive
+    #[must_use]
+    pub fn log10(self) -> Option<Self> {
+        self.map_f64(f64::log10)
+    }
+
+    /// `e^self`, named apart from `exp` which is `10^D`, returns `None` on overflow
+    #[must_use]
+    pub fn exp_e(self) -> Option<Self> {
+        self.map_f64(f64::exp)
+    }
+}
+
+/// Trigonometric functions taking value as radians, computed by `libm` through `f64`
+/// round trip, so results are rounded to `D` digits subject to `f64` precision
+#[cfg(feature = "libm")]
+impl<T: Copy + ToPrimitive + num_traits::NumCast, const D: u8> FixedPoint<T, D> {
+    /// Returns `None` if result is out of range
+    #[must_use]
+    pub fn sin(self) -> Option<Self> {
+        self.map_f64(libm::sin)
+    }
+
+    /// Returns `None` if result is out of range
+    #[must_use]
+    pub fn cos(self) -> Option<Self> {
+        self.map_f64(libm::cos)
+    }
+
+    /// Returns `None` if result is out of range, e.g. near odd multiples of `π/2`
+    #[must_use]
+    pub fn tan(self) -> Option<Self> {
+        self.map_f64(libm::tan)
+    }
+}
+
+impl<T: Copy + Number + ToPrimitive, const D: u8> FixedPoint<T, D> {
+    /// Same as `Into<f32>` for any backing type, returns `None` only if `T` can't be
+    /// represented as `f64`, which never happens to primitive integers
+    #[must_use]
+    pub fn try_into_f32(self) -> Option<f32> {
+        Some((self.0.to_f64()? / Self::EXP_T.to_f64()?) as f32)
+    }
+}
+
+impl<T: Copy + Into<i32>, const D: u8> From<FixedPoint<T, D>> for f32 {
+    fn from(value: FixedPoint<T, D>) -> f32 {
+        let raw: i32 = value.0.into();
+        raw as f32 / FixedPoint::<i32, D>::EXP_T as f32
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// Integer or fractional part is empty
+    Empty,
+    /// Character is not an ASCII decimal digit
+    InvalidDigit(char),
+    /// Value out of range of backing type
+    Overflow,
+    /// Nonzero fractional digits beyond decimal length
+    PrecisionLoss,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Empty integer or fractional part"),
+            Self::InvalidDigit(c) => write!(f, "Invalid digit {:?}", c),
+            Self::Overflow => write!(f, "Value out of range"),
+            Self::PrecisionLoss => write!(f, "Too many fractional digits"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+fn validate_digits(digits: &str) -> Result<&str, ParseError> {
+    match digits.chars().find(|c| !c.is_ascii_digit()) {
+        _ if digits.is_empty() => Err(ParseError::Empty),
+        Some(c) => Err(ParseError::InvalidDigit(c)),
+        None => Ok(digits),
+    }
+}
+
+/// Parse `[+-]digits[.digits]`, fractional digits beyond `D` are truncated.
+///
+/// There is no negative zero, `-0` and `-0.0` parse to the same value as `0`
+impl<T: convert::TryFrom<isize>, const D: u8> FromStr for FixedPoint<T, D> {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_separator(string, '.')
+    }
+}
+
+/// Same as `FromStr`
+impl<T: convert::TryFrom<isize>, const D: u8> convert::TryFrom<&str> for FixedPoint<T, D> {
+    type Error = ParseError;
+
+    fn try_from(string: &str) -> Result<Self, ParseError> {
+        string.parse()
+    }
+}
+
+impl<T: convert::TryFrom<isize>, const D: u8> FixedPoint<T, D> {
+    /// Same as `from_str` on ASCII bytes, e.g. `b"1.5"`, non-ASCII byte is reported as
+    /// `ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER)`
+    pub fn from_ascii(bytes: &[u8]) -> Result<Self, ParseError> {
+        let non_ascii = ParseError::InvalidDigit(char::REPLACEMENT_CHARACTER);
+        let string = core::str::from_utf8(bytes).map_err(|_| non_ascii)?;
+        string.parse().map_err(|error| match error {
+            ParseError::InvalidDigit(c) if !c.is_ascii() => non_ascii,
+            error => error,
+        })
+    }
+
+    /// Same as `from_str` with `separator` in place of `.`, e.g. `1,5` for `,`
+    pub fn from_str_with_separator(string: &str, separator: char) -> Result<Self, ParseError> {
+        match string.split_once(separator) {
+            Some((integer, field)) => Self::parse_parts(integer, Some(field)),
+            None => Self::parse_parts(string, None),
+        }
+    }
+
+    /// Same as `from_str` on `integer` and `fraction` joined by `.`, sign goes with
+    /// `integer` and empty `fraction` means no fractional part, e.g. `("-1", "5")` is `-1.5`
+    pub fn from_int_frac_str(integer: &str, fraction: &str) -> Result<Self, ParseError> {
+        Self::parse_parts(integer, Some(fraction).filter(|f| !f.is_empty()))
+    }
+
+    /// Same as `from_str`, but allows single spaces grouping fractional digits,
+    /// e.g. `1.234 567`, spaces elsewhere are `InvalidDigit(' ')`
+    pub fn from_str_grouped(string: &str) -> Result<Self, ParseError> {
+        let Some((integer, fraction)) = string.split_once('.') else {
+            return Self::parse_parts(string, None);
+        };
+        if fraction.starts_with(' ') || fraction.ends_with(' ') || fraction.contains("  ") {
+            return Err(ParseError::InvalidDigit(' '));
+        }
+        // Digits beyond any valid D are validated but dropped
+        let mut digits = [0; <u128 as Number>::MAX_DECIMAL_LENGTH as usize];
+        let mut length = 0;
+        for c in fraction.chars().filter(|&c| c != ' ') {
+            if !c.is_ascii_digit() {
+                return Err(ParseError::InvalidDigit(c));
+            }
+            if length < digits.len() {
+                digits[length] = c as u8;
+                length += 1;
+            }
+        }
+        let digits = core::str::from_utf8(&digits[..length]).unwrap();
+        Self::parse_parts(integer, Some(digits))
+    }
+
+    fn parse_parts(integer: &str, field: Option<&str>) -> Result<Self, ParseError> {
+        let negative = integer.starts_with('-');
+        let integer = integer.strip_prefix(['-', '+']).unwrap_or(integer);
+        let integer = validate_digits(integer)?;
+        let integer = integer.parse::<isize>().map_err(|_| ParseError::Overflow)?;
+        let (mut fraction, mut digits) = (0, 0);
+        if let Some(field) = field {
+            let field = validate_digits(field)?;
+            let kept = &field[..core::cmp::min(field.len(), D as usize)];
+            if !kept.is_empty() {
+                fraction = kept.parse::<isize>().map_err(|_| ParseError::Overflow)?;
+                digits = kept.len() as u8;
+            }
+        }
+        let mut number =
+            isize::from_parts(integer, fraction, digits, D).ok_or(ParseError::Overflow)?;
+        if negative {
+            number = -number;
+        }
+        T::try_from(number)
+            .map(Self)
+            .map_err(|_| ParseError::Overflow)
+    }
+
+    /// Same as `from_str`, but returns `ParseError::PrecisionLoss` instead of truncating
+    /// nonzero fractional digits beyond `D`
+    pub fn from_str_exact(string: &str) -> Result<Self, ParseError> {
+        let value = string.parse()?;
+        let fraction = string.split_once('.').map(|(_, f)| f).unwrap_or_default();
+        if fraction.bytes().skip(D as usize).any(|b| b != b'0') {
+            return Err(ParseError::PrecisionLoss);
+        }
+        Ok(value)
+    }
+
+    /// Parse leading `[+-]digits[.digits]` of `string` like `from_str`, returns
+    /// the value along with byte offset right after it, e.g. `1.5rest` gives `(1.5, 3)`
+    pub fn parse_prefix(string: &str) -> Result<(Self, usize), ParseError> {
+        let bytes = string.as_bytes();
+        let digits = |start: usize| {
+            let count = bytes[start..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            start + count
+        };
+        let sign = matches!(bytes.first(), Some(b'-' | b'+')) as usize;
+        let mut end = digits(sign);
+        if bytes.get(end) == Some(&b'.') && bytes.get(end + 1).is_some_and(u8::is_ascii_digit) {
+            end = digits(end + 1);
+        }
+        Ok((string[..end].parse()?, end))
+    }
+}
+
+impl<T: convert::TryFrom<i128> + Number, const D: u8> FixedPoint<T, D> {
+    /// Liberal parser for user input, unlike `from_str` it
+    ///
+    /// * trims surrounding whitespace
+    /// * ignores `_` anywhere among digits, e.g. `1_000.000_1`
+    /// * accepts missing integer or fractional digits around `.`, e.g. `.5` and `5.`
+    /// * saturates at `T::min_value()` or `T::max_value()` on overflow, note that
+    ///   `u128` values beyond `i128::MAX` saturate as well
+    ///
+    /// Fractional digits beyond `D` are truncated like `from_str`, and
+    /// `ParseError::Empty` or `ParseError::InvalidDigit` is still returned
+    /// if there's no digit at all or any other character
+    pub fn from_str_lenient(string: &str) -> Result<Self, ParseError> {
+        let string = string.trim();
+        let negative = string.starts_with('-');
+        let unsigned = string.strip_prefix(['-', '+']).unwrap_or(string);
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let mut digits = 0;
+        let mut accumulate = |part: &str, limit: usize| {
+            let (mut value, mut kept) = (Some(0_i128), 0);
+            for c in part.chars().filter(|&c| c != '_') {
+                let digit = c.to_digit(10).ok_or(ParseError::InvalidDigit(c))?;
+                digits += 1;
+                if kept < limit {
+                    value = value.and_then(|v| v.checked_mul(10)?.checked_add(digit as i128));
+                    kept += 1;
+                }
+            }
+            Ok((value, kept as u8))
+        };
+        let (integer, _) = accumulate(integer, usize::MAX)?;
+        let (fraction, kept) = accumulate(fraction, D as usize)?;
+        if digits == 0 {
+            return Err(ParseError::Empty);
+        }
+        let raw = integer.zip(fraction);
+        let raw = raw.and_then(|(integer, fraction)| i128::from_parts(integer, fraction, kept, D));
+        let raw = raw.map(|raw| if negative { -raw } else { raw });
+        Ok(match raw.and_then(|raw| T::try_from(raw).ok()) {
+            Some(raw) => Self(raw),
+            None if negative => Self(T::min_value()),
+            None => Self(T::max_value()),
+        })
+    }
+}
+
+/// Trailing zeros of fraction are trimmed and whole numbers end with `.0`,
+/// alternate flag `{:#}` omits the `.0`.
+///
+/// Precision `{:.N}` pads with zeros or rounds half away from zero to exactly `N` digits
+impl<T, const D: u8> Display for FixedPoint<T, D>
+where
+    T: Copy
+        + Display
+        + Number
+        + PartialOrd
+        + ops::Add<Output = T>
+        + ops::Sub<Output = T>
+        + ops::Div<Output = T>
+        + ops::Rem<Output = T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let () = Self::VALID_DECIMAL_LENGTH;
+        fmt_scaled(f, self.0, D)
+    }
+}
+
+/// Format `raw` in units of `10^-decimal_length` as `Display` of `FixedPoint` does
+fn fmt_scaled<T>(f: &mut core::fmt::Formatter, raw: T, decimal_length: u8) -> core::fmt::Result
+where
+    T: Copy
+        + Display
+        + Number
+        + PartialOrd
+        + ops::Add<Output = T>
+        + ops::Sub<Output = T>
+        + ops::Div<Output = T>
+        + ops::Rem<Output = T>,
+{
+    let (zero, one) = (T::zero(), T::POWERS_OF_TEN[0]);
+    let negative = raw < zero;
+    let exp = T::POWERS_OF_TEN[decimal_length as usize];
+    let (mut integer, mut decimal) = (raw / exp, raw % exp);
+    if negative && decimal_length > 0 {
+        // |integer| <= T::MAX / 10 so negating never overflows
+        integer = zero - integer;
+        decimal = zero - decimal;
+    }
+    let mut length = decimal_length as usize;
+    let mut zeros = 0;
+    match f.precision() {
+        Some(precision) if precision < decimal_length as usize => {
+            length = precision;
+            let exp = T::POWERS_OF_TEN[decimal_length as usize - length];
+            let remainder = decimal % exp;
+            decimal = decimal / exp;
+            if remainder >= exp - remainder {
+                decimal = decimal + one;
+            }
+            if decimal == T::POWERS_OF_TEN[length] {
+                decimal = zero;
+                integer = integer + one;
+            }
+        }
+        Some(precision) => zeros = precision - decimal_length as usize,
+        None if decimal == zero => length = 0,
+        None => {
+            while decimal % T::ten() == zero {
+                decimal = decimal / T::ten();
+                length -= 1;
+            }
+        }
+    }
+    if negative && decimal_length > 0 && (integer != zero || decimal != zero) {
+        write!(f, "-")?;
+    }
+    write!(f, "{}", integer)?;
+    if f.precision().is_none() && length == 0 {
+        return if f.alternate() {
+            Ok(())
+        } else {
+            write!(f, ".0")
+        };
+    }
+    if length + zeros > 0 {
+        write!(f, ".")?;
+    }
+    if length > 0 {
+        write!(f, "{:0length$}", decimal, length = length)?;
+    }
+    write!(f, "{:0<zeros$}", "", zeros = zeros)
+}
+
+struct ByteWriter<'a> {
+    bytes: &'a mut [u8],
+    length: usize,
+}
+
+impl core::fmt::Write for ByteWriter<'_> {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        let end = self.length + string.len();
+        let slice = self
+            .bytes
+            .get_mut(self.length..end)
+            .ok_or(core::fmt::Error)?;
+        slice.copy_from_slice(string.as_bytes());
+        self.length = end;
+        Ok(())
+    }
+}
+
+impl<T: Number, const D: u8> FixedPoint<T, D>
+where
+    Self: Display,
+{
+    /// Worst case length of `Display` output, i.e. digits of `T::MAX` plus sign and dot,
+    /// along with trailing `0` of `.0` when `D` is zero
+    pub const DISPLAY_LENGTH: usize = T::MAX_DECIMAL_LENGTH as usize + 3 + (D == 0) as usize;
+
+    /// Write `Display` representation into `writer` without allocation,
+    /// e.g. a `heapless::String` or UART wrapper
+    pub fn write_display<W: core::fmt::Write>(&self, writer: &mut W) -> core::fmt::Result {
+        write!(writer, "{}", self)
+    }
+
+    /// Format into a byte array without heap allocation, returns the array and filled length.
+    ///
+    /// `N` is expected to be `DISPLAY_LENGTH`, smaller `N` fails to compile
+    #[must_use]
+    pub fn to_bytes_array<const N: usize>(self) -> ([u8; N], usize) {
+        const { assert!(N >= Self::DISPLAY_LENGTH, "N less than DISPLAY_LENGTH") };
+        use core::fmt::Write;
+        let mut bytes = [0; N];
+        let mut writer = ByteWriter {
+            bytes: &mut bytes,
+            length: 0,
+        };
+        write!(writer, "{}", self).expect("DISPLAY_LENGTH too small");
+        let length = writer.length;
+        (bytes, length)
+    }
+
+    /// Format into first `width` bytes of `buffer` right aligned, padded with `fill`,
+    /// zero padding goes after sign, e.g. `-001.5`.
+    ///
+    /// Returns error if `buffer` is shorter than `width`, value doesn't fit into `width`
+    /// or `fill` isn't ASCII
+    pub fn to_padded_bytes(
+        self,
+        width: usize,
+        fill: u8,
+        buffer: &mut [u8],
+    ) -> Result<&str, core::fmt::Error> {
+        use core::fmt::Write;
+        let bytes = buffer.get_mut(..width).ok_or(core::fmt::Error)?;
+        let mut writer = ByteWriter { bytes, length: 0 };
+        write!(writer, "{}", self)?;
+        let (length, padding) = (writer.length, width - writer.length);
+        bytes.copy_within(..length, padding);
+        bytes[..padding].fill(fill);
+        if fill == b'0' && padding > 0 && bytes[padding] == b'-' {
+            bytes.swap(0, padding);
+        }
+        core::str::from_utf8(bytes).map_err(|_| core::fmt::Error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    Self: Display,
+{
+    /// Format as percentage with trailing zeros trimmed, e.g. `0.125` as `12.5%`,
+    /// decimal point is moved rather than scaling raw value so it never overflows
+    #[must_use]
+    pub fn to_percent_string(self) -> String {
+        let string = format!("{:.*}", D as usize, self);
+        let (sign, unsigned) = match string.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", string.as_str()),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let fraction = format!("{:0<2}", fraction);
+        let integer = format!("{}{}", integer, &fraction[..2]);
+        let integer = integer.trim_start_matches('0');
+        let integer = if integer.is_empty() { "0" } else { integer };
+        match fraction[2..].trim_end_matches('0') {
+            "" => format!("{}{}%", sign, integer),
+            fraction => format!("{}{}.{}%", sign, integer, fraction),
+        }
+    }
+}
+
+struct DebugVerbose<T, const D: u8>(FixedPoint<T, D>);
+
+impl<T: Display, const D: u8> Display for DebugVerbose<T, D>
+where
+    FixedPoint<T, D>: Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{:.*} (raw={}, D={})", D as usize, self.0, self.0 .0, D)
+    }
+}
+
+impl<T: Display, const D: u8> FixedPoint<T, D>
+where
+    Self: Display,
+{
+    /// Format with exactly `D` fractional digits along with raw value, for diagnosing
+    /// scaling issues, e.g. `1.50 (raw=150, D=2)`
+    #[must_use]
+    pub fn debug_verbose(self) -> impl Display {
+        DebugVerbose(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, const D: u8> FixedPoint<T, D>
+where
+    Self: Display,
+{
+    /// Format with exactly `D` fractional digits without trimming, e.g. `1.5000` for `D = 4`
+    #[must_use]
+    pub fn to_fixed_string(self) -> String {
+        format!("{:.*}", D as usize, self)
+    }
+
+    /// Same as `Display` with `separator` inserted every three integer digits,
+    /// e.g. `-1234567.5` as `-1,234,567.5`
+    #[must_use]
+    pub fn to_grouped_string(self, separator: char) -> String {
+        let string = self.to_string();
+        let (sign, unsigned) = match string.strip_prefix('-') {
+            Some(unsigned) => ("-", unsigned),
+            None => ("", string.as_str()),
+        };
+        let (integer, fraction) = unsigned.split_at(unsigned.find('.').unwrap_or(unsigned.len()));
+        let mut grouped = String::from(sign);
+        for (index, c) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(c);
+        }
+        grouped + fraction
+    }
+
+    /// Same as `Display` with `separator` in place of `.`
+    #[must_use]
+    pub fn fmt_with_separator(&self, separator: char) -> String {
+        self.to_string()
+            .replacen('.', separator.encode_utf8(&mut [0; 4]), 1)
+    }
+
+    /// Integer and trimmed fractional digits as in `Display`, sign stays with integer part,
+    /// e.g. `-1.50` is `("-1", "5")` and whole numbers have empty fractional part
+    #[must_use]
+    pub fn split_int_frac_str(self) -> (String, String) {
+        let string = format!("{:#}", self);
+        match string.split_once('.') {
+            Some((integer, fraction)) => (integer.to_owned(), fraction.to_owned()),
+            None => (string, String::new()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Display, const D: u8> FixedPoint<T, D> {
+    /// Format as mantissa and exponent of 10 with trailing zeros trimmed,
+    /// e.g. `150.0` as `1.5e2`, `-0.015` as `-1.5e-2` and `0.0` as `0e0`
+    #[must_use]
+    pub fn to_scientific_string(&self) -> String {
+        let raw = self.0.to_string();
+        let (sign, digits) = match raw.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", raw.as_str()),
+        };
+        if digits == "0" {
+            return "0e0".to_owned();
+        }
+        let exponent = digits.len() as isize - 1 - D as isize;
+        let (first, rest) = digits.split_at(1);
+        match rest.trim_end_matches('0') {
+            "" => format!("{}{}e{}", sign, first, exponent),
+            rest => format!("{}{}.{}e{}", sign, first, rest, exponent),
+        }
+    }
+}
+
+/// Same as `Display`, e.g. `1.5`
+#[cfg(feature = "std")]
+impl<T, const D: u8> From<FixedPoint<T, D>> for String
+where
+    FixedPoint<T, D>: Display,
+{
+    fn from(value: FixedPoint<T, D>) -> Self {
+        value.to_string()
+    }
+}
+
+/// Epsilon is `FixedPoint` of same type, defaults to `STEP`, i.e. one unit of last digit
+#[cfg(feature = "approx")]
+impl<T: Copy + Number + PartialEq + Widen, const D: u8> approx::AbsDiffEq for FixedPoint<T, D> {
+    type Epsilon = Self;
+
+    fn default_epsilon() -> Self {
+        Self::STEP
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self) -> bool {
+        let (a, b) = (self.0.into(), other.0.into());
+        let difference = if a > b { a - b } else { b - a };
+        difference <= epsilon.0.into()
+    }
+}
+
+/// Max relative is a fraction in `FixedPoint`, e.g. `0.01` for 1%, defaults to `STEP`
+#[cfg(feature = "approx")]
+impl<T: Copy + Number + PartialEq + Widen, const D: u8> approx::RelativeEq for FixedPoint<T, D> {
+    fn default_max_relative() -> Self {
+        Self::STEP
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool {
+        use approx::AbsDiffEq;
+        if self.abs_diff_eq(other, epsilon) {
+            return true;
+        }
+        let (a, b) = (self.0.into(), other.0.into());
+        let difference = if a > b { a - b } else { b - a };
+        let zero: T::Wide = num_traits::Zero::zero();
+        let abs = |value: T::Wide| if value < zero { zero - value } else { value };
+        let largest = core::cmp::max(abs(a), abs(b));
+        // Wide enough for product of two values of T
+        difference <= largest * max_relative.0.into() / Self::EXP_T.into()
+    }
+}
+
+#[cfg(feature = "rust-decimal")]
+macro_rules! impl_from_fixed_for_decimal {
+    ($($types:ty),+) => {
+        $(
+            /// Exact conversion keeping scale `D`, 64-bit raw values and `D <= 19`
+            /// always fit in `Decimal`
+            impl<const D: u8> From<FixedPoint<$types, D>> for rust_decimal::Decimal {
+                fn from(value: FixedPoint<$types, D>) -> Self {
+                    Self::from_i128_with_scale(value.0 as i128, D as u32)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "rust-decimal")]
+impl_from_fixed_for_decimal!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+/// Exact conversion keeping scale `D`, returns `ParseError::Overflow` if raw value
+/// exceeds 96 bits or `D > 28`
+#[cfg(feature = "rust-decimal")]
+impl<const D: u8> convert::TryFrom<FixedPoint<i128, D>> for rust_decimal::Decimal {
+    type Error = ParseError;
+
+    fn try_from(value: FixedPoint<i128, D>) -> Result<Self, ParseError> {
+        Self::try_from_i128_with_scale(value.0, D as u32).map_err(|_| ParseError::Overflow)
+    }
+}
+
+/// Exact conversion, trailing zeros beyond `D` are dropped, returns
+/// `ParseError::PrecisionLoss` if scale still exceeds `D` or `ParseError::Overflow`
+/// if out of range
+#[cfg(feature = "rust-decimal")]
+impl<T: convert::TryFrom<i128>, const D: u8> convert::TryFrom<rust_decimal::Decimal>
+    for FixedPoint<T, D>
+{
+    type Error = ParseError;
+
+    fn try_from(mut decimal: rust_decimal::Decimal) -> Result<Self, ParseError> {
+        if decimal.scale() > D as u32 {
+            decimal = decimal.normalize();
+        }
+        let Some(digits) = (D as u32).checked_sub(decimal.scale()) else {
+            return Err(ParseError::PrecisionLoss);
+        };
+        let exp = i128::POWERS_OF_TEN.get(digits as usize);
+        let raw = exp.and_then(|exp| decimal.mantissa().checked_mul(*exp));
+        let raw = raw.and_then(|raw| T::try_from(raw).ok());
+        raw.map(Self).ok_or(ParseError::Overflow)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>, const D: u8> arbitrary::Arbitrary<'a> for FixedPoint<T, D> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
+
+/// Strategy generating any value of backing type
+#[cfg(feature = "proptest")]
+pub fn any_fixed_point<T, const D: u8>(
+) -> impl proptest::strategy::Strategy<Value = FixedPoint<T, D>>
+where
+    T: proptest::arbitrary::Arbitrary,
+{
+    use proptest::strategy::Strategy;
+    proptest::arbitrary::any::<T>().prop_map(FixedPoint)
+}
+
 #[cfg(feature = "serde")]
 impl<T: Copy + Into<i32>, const D: u8> serde::Serialize for FixedPoint<T, D> {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {